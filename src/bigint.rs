@@ -0,0 +1,647 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub,
+        SubAssign,
+    },
+    str::FromStr,
+};
+
+use crate::{
+    integer::Int,
+    scalar::{Cast, Lerp, MulAdd, Ring, Semiring, Sqrt},
+};
+
+// arbitrary precision signed integer stored as sign and magnitude, the
+// magnitude is a little-endian vector of 32 bit limbs without trailing
+// zeros so zero is the empty vector and the derived equality is value
+// equality
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    neg: bool,
+    mag: Vec<u32>,
+}
+
+fn trim(mut mag: Vec<u32>) -> Vec<u32> {
+    while mag.last() == Some(&0) {
+        mag.pop();
+    }
+    mag
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut ret = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let s = x as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        ret.push(s as u32);
+        carry = s >> 32;
+    }
+    if carry != 0 {
+        ret.push(carry as u32);
+    }
+    ret
+}
+
+// requires |a| >= |b|
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut ret = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let d = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        ret.push(d as u32);
+        borrow = (d < 0) as i64;
+    }
+    trim(ret)
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let mut ret = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u64 * y as u64 + ret[i + j] as u64 + carry;
+            ret[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        ret[i + b.len()] = carry as u32;
+    }
+    trim(ret)
+}
+
+fn shl_mag(a: &[u32], s: u32) -> Vec<u32> {
+    if s == 0 {
+        return a.to_vec();
+    }
+    let mut ret = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for &x in a {
+        ret.push(x << s | carry);
+        carry = x >> (32 - s);
+    }
+    ret.push(carry);
+    ret
+}
+
+fn divrem_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut q = vec![0u32; a.len()];
+    let mut r = 0u64;
+    for i in (0..a.len()).rev() {
+        let cur = r << 32 | a[i] as u64;
+        q[i] = (cur / d as u64) as u32;
+        r = cur % d as u64;
+    }
+    (trim(q), r as u32)
+}
+
+// schoolbook long division, Knuth's algorithm D
+fn divrem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "division by zero");
+
+    if cmp_mag(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = divrem_small(a, b[0]);
+        return (q, trim(vec![r]));
+    }
+
+    // normalize so the top limb of the divisor has its high bit set
+    let s = b[b.len() - 1].leading_zeros();
+    let v = trim(shl_mag(b, s));
+    let mut u = shl_mag(a, s);
+    if u.len() == a.len() {
+        u.push(0);
+    }
+
+    let n = v.len();
+    let m = u.len() - n;
+    let mut q = vec![0u32; m];
+    let base = 1u64 << 32;
+
+    for j in (0..m).rev() {
+        let num = (u[j + n] as u64) << 32 | u[j + n - 1] as u64;
+        let mut qhat = num / v[n - 1] as u64;
+        let mut rhat = num % v[n - 1] as u64;
+        while qhat >= base
+            || qhat * v[n - 2] as u64 > (rhat << 32 | u[j + n - 2] as u64)
+        {
+            qhat -= 1;
+            rhat += v[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        let mut k = 0i64;
+        for i in 0..n {
+            let p = qhat * v[i] as u64;
+            let t = u[i + j] as i64 - k - (p & 0xffff_ffff) as i64;
+            u[i + j] = t as u32;
+            k = (p >> 32) as i64 - (t >> 32);
+        }
+        let t = u[j + n] as i64 - k;
+        u[j + n] = t as u32;
+
+        // qhat was one too large, add the divisor back
+        if t < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let t = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = t as u32;
+                carry = t >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        q[j] = qhat as u32;
+    }
+
+    let mut r = trim(u[..n].to_vec());
+    if s != 0 {
+        for i in 0..r.len() {
+            let hi = r.get(i + 1).map_or(0, |&x| x << (32 - s));
+            r[i] = r[i] >> s | hi;
+        }
+        r = trim(r);
+    }
+    (trim(q), r)
+}
+
+impl BigInt {
+    fn from_parts(neg: bool, mag: Vec<u32>) -> Self {
+        let mag = trim(mag);
+        BigInt {
+            neg: neg && !mag.is_empty(),
+            mag,
+        }
+    }
+
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.neg
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.mag.clone())
+    }
+
+    pub fn bits(&self) -> u64 {
+        match self.mag.last() {
+            Some(top) => {
+                self.mag.len() as u64 * 32 - top.leading_zeros() as u64
+            }
+            None => 0,
+        }
+    }
+
+    // truncated division, the remainder has the sign of `self`
+    pub fn div_rem(&self, rhs: &BigInt) -> (BigInt, BigInt) {
+        let (q, r) = divrem_mag(&self.mag, &rhs.mag);
+        (
+            BigInt::from_parts(self.neg != rhs.neg, q),
+            BigInt::from_parts(self.neg, r),
+        )
+    }
+
+    pub fn pow(&self, mut e: u64) -> BigInt {
+        let mut base = self.clone();
+        let mut acc = BigInt::from(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = &acc * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        acc
+    }
+
+    // floor of the square root through Newton's iteration
+    pub fn isqrt(&self) -> BigInt {
+        assert!(!self.neg, "square root of a negative number");
+        if self.is_zero() {
+            return BigInt::zero();
+        }
+
+        let mut x = BigInt::from(1).shl(self.bits().div_ceil(2));
+        loop {
+            let y = (&x + &(self / &x)).shr(1);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    pub fn shl(&self, n: u64) -> BigInt {
+        let mut mag = vec![0u32; (n / 32) as usize];
+        mag.extend(shl_mag(&self.mag, (n % 32) as u32));
+        BigInt::from_parts(self.neg, mag)
+    }
+
+    // shifts the magnitude, so negative values round toward zero
+    pub fn shr(&self, n: u64) -> BigInt {
+        let limbs = (n / 32) as usize;
+        if limbs >= self.mag.len() {
+            return BigInt::zero();
+        }
+        let s = (n % 32) as u32;
+        let mut mag = self.mag[limbs..].to_vec();
+        if s != 0 {
+            for i in 0..mag.len() {
+                let hi = mag.get(i + 1).map_or(0, |&x| x << (32 - s));
+                mag[i] = mag[i] >> s | hi;
+            }
+        }
+        BigInt::from_parts(self.neg, mag)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        // the top 64 significant bits are enough for a correctly scaled
+        // approximation
+        let bits = self.bits();
+        let shift = bits.saturating_sub(64);
+        let top = self.shr(shift);
+        let mut v = 0.;
+        for &limb in top.mag.iter().rev() {
+            v = v * 4294967296. + limb as f64;
+        }
+        v *= 2f64.powi(shift.min(i32::MAX as u64) as i32);
+        if self.neg {
+            -v
+        } else {
+            v
+        }
+    }
+
+    // rounds toward zero
+    pub fn from_f64(v: f64) -> BigInt {
+        assert!(v.is_finite(), "value must be finite");
+
+        let t = v.abs().trunc();
+        if t < 1. {
+            return BigInt::zero();
+        }
+        let bits = t.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as i64 - 1075;
+        let mantissa = bits & ((1 << 52) - 1) | 1 << 52;
+        let m = BigInt::from(mantissa as i64);
+        let m = if exp >= 0 {
+            m.shl(exp as u64)
+        } else {
+            m.shr(-exp as u64)
+        };
+        if v < 0. {
+            -m
+        } else {
+            m
+        }
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut m = value.unsigned_abs();
+        let mut mag = vec![];
+        while m != 0 {
+            mag.push(m as u32);
+            m >>= 32;
+        }
+        BigInt::from_parts(value < 0, mag)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err("invalid digit found in string");
+        }
+
+        let mut mag = vec![];
+        for chunk in digits.as_bytes().chunks(9) {
+            let mut v = 0u64;
+            for &b in chunk {
+                v = v * 10 + (b - b'0') as u64;
+            }
+            let mut carry = v;
+            let scale = 10u64.pow(chunk.len() as u32);
+            for limb in mag.iter_mut() {
+                let t = *limb as u64 * scale + carry;
+                *limb = t as u32;
+                carry = t >> 32;
+            }
+            if carry != 0 {
+                mag.push(carry as u32);
+            }
+        }
+        Ok(BigInt::from_parts(neg, mag))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks = vec![];
+        let mut mag = self.mag.clone();
+        while !mag.is_empty() {
+            let (q, r) = divrem_small(&mag, 1_000_000_000);
+            chunks.push(r);
+            mag = q;
+        }
+
+        if self.neg {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.pop().unwrap())?;
+        for c in chunks.iter().rev() {
+            write!(f, "{:09}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.neg, other.neg) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Int for BigInt {
+    fn zero() -> Self {
+        BigInt::zero()
+    }
+
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self / rhs)
+    }
+
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self % rhs)
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self.clone())
+    }
+
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+
+    fn div_euclid(&self, rhs: &Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        match (r.neg, rhs.neg) {
+            (true, false) => q - BigInt::from(1),
+            (true, true) => q + BigInt::from(1),
+            _ => q,
+        }
+    }
+
+    fn rem_euclid(&self, rhs: &Self) -> Self {
+        let r = self % rhs;
+        if r.neg {
+            BigInt::from_parts(false, sub_mag(&rhs.mag, &r.mag))
+        } else {
+            r
+        }
+    }
+
+    fn isqrt(&self) -> Self {
+        BigInt::isqrt(self)
+    }
+
+    fn to_f64(&self) -> f64 {
+        BigInt::to_f64(self)
+    }
+
+    fn from_f64(v: f64) -> Option<Self> {
+        v.is_finite().then(|| BigInt::from_f64(v))
+    }
+}
+
+impl Semiring for BigInt {
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+
+    fn is_non_zero(&self) -> bool {
+        !self.is_zero()
+    }
+
+    const EXACT: bool = true;
+}
+
+impl Ring for BigInt {}
+
+impl Sqrt for BigInt {
+    // rounds down for non-squares
+    fn sqrt(self) -> Self {
+        self.isqrt()
+    }
+}
+
+impl Cast<BigInt> for BigInt {
+    fn cast(self) -> BigInt {
+        self
+    }
+}
+
+impl Cast<f64> for BigInt {
+    fn cast(self) -> f64 {
+        self.to_f64()
+    }
+}
+
+impl Lerp for BigInt {
+    // the factor is applied in 2^-24 steps and the result truncated toward
+    // zero
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => {
+                let p = BigInt::from((p as f64 * 16777216.).round() as i64);
+                let d = (&v - &u) * p;
+                u + d / BigInt::from(1 << 24)
+            }
+        }
+    }
+}
+
+impl MulAdd<BigInt, BigInt> for BigInt {
+    fn mul_add(self, a: &BigInt, b: &BigInt) -> Self {
+        &(&self * a) + b
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(BigInt::zero(), |a, b| a + b)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.neg, self.mag)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.neg == rhs.neg {
+            return BigInt::from_parts(self.neg, add_mag(&self.mag, &rhs.mag));
+        }
+        match cmp_mag(&self.mag, &rhs.mag) {
+            Ordering::Less => {
+                BigInt::from_parts(rhs.neg, sub_mag(&rhs.mag, &self.mag))
+            }
+            _ => BigInt::from_parts(self.neg, sub_mag(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs.clone()
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(self.neg != rhs.neg, mul_mag(&self.mag, &rhs.mag))
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+macro_rules! ops {
+    ($tr:ident, $f:ident, $atr:ident, $af:ident) => {
+        impl $tr for BigInt {
+            type Output = BigInt;
+            fn $f(self, rhs: BigInt) -> BigInt {
+                (&self).$f(&rhs)
+            }
+        }
+
+        impl $tr<&BigInt> for BigInt {
+            type Output = BigInt;
+            fn $f(self, rhs: &BigInt) -> BigInt {
+                (&self).$f(rhs)
+            }
+        }
+
+        impl $atr for BigInt {
+            fn $af(&mut self, rhs: BigInt) {
+                *self = (&*self).$f(&rhs);
+            }
+        }
+    };
+}
+
+ops!(Add, add, AddAssign, add_assign);
+ops!(Sub, sub, SubAssign, sub_assign);
+ops!(Mul, mul, MulAssign, mul_assign);
+ops!(Div, div, DivAssign, div_assign);
+
+impl Rem for BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: BigInt) -> BigInt {
+        &self % &rhs
+    }
+}
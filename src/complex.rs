@@ -6,45 +6,43 @@ use std::{
     },
 };
 
-use crate::{
-    matrix::Transpose,
-    scalar::{Lerp, MulAdd, Scalar, Sqrt},
-    utils::EPSILON,
-    vector::Angle,
-    Dot, Matrix, Vector, V,
+use crate::scalar::{
+    Cast, ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring, Sqrt,
 };
 
 #[derive(Copy, Clone, Default, PartialEq, PartialOrd)]
-pub struct Complex {
-    pub x: f64,
-    pub y: f64,
+pub struct Complex<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
-pub trait Conj {
-    fn conj(&self) -> Complex;
-}
+pub type Complex32 = Complex<f32>;
+pub type Complex64 = Complex<f64>;
 
 #[macro_export]
 macro_rules! C {
     ($r:expr, $i:expr) => {
-        Complex::from([$r, $i])
+        Complex::<f64>::from([$r, $i])
+    };
+    ($r:expr, $i:expr; $t:ty) => {
+        Complex::<$t>::from([$r, $i])
     };
 }
 
-impl Debug for Complex {
+impl<T: Float> Debug for Complex<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({} + {}i)", self.x, self.y)
     }
 }
 
-impl Display for Complex {
+impl<T: Float> Display for Complex<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({} + {}i)", self.x, self.y)
     }
 }
 
-impl From<[f64; 2]> for Complex {
-    fn from(value: [f64; 2]) -> Self {
+impl<T: Float> From<[T; 2]> for Complex<T> {
+    fn from(value: [T; 2]) -> Self {
         Complex {
             x: value[0],
             y: value[1],
@@ -52,81 +50,247 @@ impl From<[f64; 2]> for Complex {
     }
 }
 
-impl Scalar for Complex {
-    type AbsOutput = f64;
+impl<T: Float> Complex<T> {
+    pub fn i() -> Self {
+        Complex {
+            x: T::default(),
+            y: T::one(),
+        }
+    }
 
-    fn abs(&self) -> Self::AbsOutput {
-        (self.x.powi(2) + self.y.powi(2)).powf(0.5)
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Complex {
+            x: r * theta.cos(),
+            y: r * theta.sin(),
+        }
     }
 
+    pub fn arg(&self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    pub fn to_polar(&self) -> (T, T) {
+        (self.abs(), self.arg())
+    }
+
+    fn norm_sqr(&self) -> T {
+        self.x * self.x + self.y * self.y
+    }
+}
+
+impl<T: Float> Semiring for Complex<T> {
+    fn one() -> Self {
+        Complex {
+            x: T::one(),
+            y: T::default(),
+        }
+    }
+
+    fn conj(self) -> Self {
+        Complex {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+
+    fn is_non_zero(&self) -> bool {
+        self.x.is_non_zero() || self.y.is_non_zero()
+    }
+}
+
+impl<T: Float> Ring for Complex<T> {}
+
+impl<T: Float> Field for Complex<T> {
     fn inv(self) -> Self {
         Complex {
-            x: self.x / (self.x.powi(2) + self.y.powi(2)),
-            y: -self.y / (self.x.powi(2) + self.y.powi(2)),
+            x: self.x / self.norm_sqr(),
+            y: -self.y / self.norm_sqr(),
         }
     }
+}
 
-    fn one() -> Self {
-        C!(1., 0.)
+impl<T: Float> ComplexField for Complex<T> {
+    type AbsOutput = T;
+
+    fn abs(&self) -> Self::AbsOutput {
+        self.norm_sqr().sqrt()
     }
 
-    type TanOutput = Complex;
+    type TanOutput = Complex<T>;
     fn tan(self) -> Self::TanOutput {
+        let two = T::one() + T::one();
+        let d = (two * self.x).cos() + (two * self.y).cosh();
         Complex {
-            x: f64::sin(2. * self.x)
-                / (f64::cos(2. * self.x) + f64::cosh(2. * self.y)),
-            y: f64::sinh(2. * self.y)
-                / (f64::cos(2. * self.x) + f64::cosh(2. * self.y)),
+            x: (two * self.x).sin() / d,
+            y: (two * self.y).sinh() / d,
         }
     }
 
-    type SinOutput = Complex;
+    type SinOutput = Complex<T>;
     fn sin(self) -> Self::SinOutput {
         Complex {
-            x: f64::sin(self.x) * f64::cosh(self.y),
-            y: f64::cos(self.x) * f64::sinh(self.y),
+            x: self.x.sin() * self.y.cosh(),
+            y: self.x.cos() * self.y.sinh(),
         }
     }
 
-    type CosOutput = Complex;
+    type CosOutput = Complex<T>;
     fn cos(self) -> Self::CosOutput {
         Complex {
-            x: f64::cos(self.x) * f64::cosh(self.y),
-            y: f64::sin(self.x) * f64::sinh(self.y),
+            x: self.x.cos() * self.y.cosh(),
+            y: -(self.x.sin() * self.y.sinh()),
         }
     }
 
-    fn is_non_zero(&self) -> bool {
-        self.x.abs() > EPSILON || self.y.abs() > EPSILON
+    fn sinh(self) -> Self {
+        Complex {
+            x: self.x.sinh() * self.y.cos(),
+            y: self.x.cosh() * self.y.sin(),
+        }
+    }
+
+    fn cosh(self) -> Self {
+        Complex {
+            x: self.x.cosh() * self.y.cos(),
+            y: self.x.sinh() * self.y.sin(),
+        }
+    }
+
+    // asin(z) = -i ln(iz + sqrt(1 - z^2))
+    fn asin(self) -> Self {
+        let i = Complex::i();
+        -i * (i * self + (Self::one() - self * self).sqrt()).ln()
+    }
+
+    // acos(z) = -i ln(z + i sqrt(1 - z^2))
+    fn acos(self) -> Self {
+        let i = Complex::i();
+        -i * (self + i * (Self::one() - self * self).sqrt()).ln()
+    }
+
+    // atan(z) = i/2 (ln(1 - iz) - ln(1 + iz))
+    fn atan(self) -> Self {
+        let i = Complex::i();
+        let half = T::from_f32(0.5);
+        i * ((Self::one() - i * self).ln() - (Self::one() + i * self).ln())
+            * half
+    }
+
+    fn exp(self) -> Self {
+        Complex::from_polar(self.x.exp(), self.y)
+    }
+
+    fn ln(self) -> Self {
+        Complex {
+            x: self.abs().ln(),
+            y: self.arg(),
+        }
+    }
+
+    // exact zeros follow the real types: 0^0 = 1, 0^n = 0 for n > 0 and
+    // negative powers of zero blow up through the general formula
+    fn powf(self, n: T) -> Self {
+        let zero = T::default();
+        if n == zero {
+            return Complex::one();
+        }
+        if self == Complex::default() && n > zero {
+            return Complex::default();
+        }
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(n), theta * n)
+    }
+
+    fn powc(self, n: Self) -> Self {
+        if n == Complex::default() {
+            return Complex::one();
+        }
+        if self == Complex::default() && n.x > T::default() {
+            return Complex::default();
+        }
+        (n * self.ln()).exp()
+    }
+
+    fn hypot(self, other: Self) -> T {
+        self.abs().hypot(other.abs())
+    }
+
+    fn real(self) -> Self::AbsOutput {
+        self.x
+    }
+}
+
+impl<T: Float> Sqrt for Complex<T> {
+    fn sqrt(self) -> Self {
+        // principal root, the imaginary part takes the sign of `y` so real
+        // inputs never divide by zero
+        let two = T::one() + T::one();
+        let y = ((self.abs() - self.x) / two).sqrt();
+        Complex {
+            x: ((self.abs() + self.x) / two).sqrt(),
+            y: if self.y < T::default() { -y } else { y },
+        }
+    }
+}
+
+impl<T: Float + Cast<U>, U: Float> Cast<Complex<U>> for Complex<T> {
+    fn cast(self) -> Complex<U> {
+        Complex {
+            x: self.x.cast(),
+            y: self.y.cast(),
+        }
+    }
+}
+
+impl<U: Float> Cast<Complex<U>> for f64
+where
+    f64: Cast<U>,
+{
+    fn cast(self) -> Complex<U> {
+        Complex {
+            x: self.cast(),
+            y: U::default(),
+        }
     }
 }
 
-impl Sqrt for Complex {
-    fn sqrt(self: Self) -> Self {
+impl<U: Float> Cast<Complex<U>> for f32
+where
+    f32: Cast<U>,
+{
+    fn cast(self) -> Complex<U> {
         Complex {
-            x: ((self.abs() + self.x) / 2.).sqrt(),
-            y: self.y / self.y.abs() * ((self.abs() - self.x) / 2.).sqrt(),
+            x: self.cast(),
+            y: U::default(),
         }
     }
 }
 
-impl Lerp for Complex {
+impl<T: Float> Lerp for Complex<T> {
     fn lerp(u: Self, v: Self, t: f32) -> Self {
         match t {
             0. => u,
             1. => v,
-            p => (v - u) * p as f64 + u,
+            p => (v - u) * T::from_f32(p) + u,
+        }
+    }
+
+    fn lerp_elem(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => (v - u).mul_add(&T::from_f32(p), &u),
         }
     }
 }
 
-impl Sum for Complex {
+impl<T: Float> Sum for Complex<T> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(Complex::default(), |a, b| a + b)
     }
 }
 
-impl Neg for Complex {
+impl<T: Float> Neg for Complex<T> {
     type Output = Self;
     fn neg(self) -> Self::Output {
         Complex {
@@ -136,7 +300,7 @@ impl Neg for Complex {
     }
 }
 
-impl Add for Complex {
+impl<T: Float> Add for Complex<T> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         Complex {
@@ -146,21 +310,21 @@ impl Add for Complex {
     }
 }
 
-impl AddAssign for Complex {
+impl<T: Float> AddAssign for Complex<T> {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
-impl AddAssign<&Complex> for Complex {
-    fn add_assign(&mut self, rhs: &Complex) {
+impl<T: Float> AddAssign<&Complex<T>> for Complex<T> {
+    fn add_assign(&mut self, rhs: &Complex<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
-impl Sub for Complex {
+impl<T: Float> Sub for Complex<T> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
         Complex {
@@ -170,21 +334,21 @@ impl Sub for Complex {
     }
 }
 
-impl SubAssign for Complex {
+impl<T: Float> SubAssign for Complex<T> {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
 
-impl SubAssign<&Complex> for Complex {
-    fn sub_assign(&mut self, rhs: &Complex) {
+impl<T: Float> SubAssign<&Complex<T>> for Complex<T> {
+    fn sub_assign(&mut self, rhs: &Complex<T>) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
 
-impl Mul for Complex {
+impl<T: Float> Mul for Complex<T> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
         Complex {
@@ -194,34 +358,30 @@ impl Mul for Complex {
     }
 }
 
-impl MulAssign for Complex {
+impl<T: Float> MulAssign for Complex<T> {
     fn mul_assign(&mut self, rhs: Self) {
-        let x = self.x * rhs.x - self.y * rhs.y;
-        self.y = self.x * rhs.y + self.y * rhs.x;
-        self.x = x;
+        *self = *self * rhs;
     }
 }
 
-impl MulAssign<&Complex> for Complex {
-    fn mul_assign(&mut self, rhs: &Complex) {
-        let x = self.x * rhs.x - self.y * rhs.y;
-        self.y = self.x * rhs.y + self.y * rhs.x;
-        self.x = x;
+impl<T: Float> MulAssign<&Complex<T>> for Complex<T> {
+    fn mul_assign(&mut self, rhs: &Complex<T>) {
+        *self = *self * *rhs;
     }
 }
 
-impl MulAdd<Complex, Complex> for Complex {
+impl<T: Float> MulAdd<Complex<T>, Complex<T>> for Complex<T> {
     fn mul_add(self, a: &Self, b: &Self) -> Self {
         Complex {
-            x: self.x.mul_add(a.x, self.y.mul_add(-a.y, b.x)),
-            y: self.x.mul_add(a.y, self.y.mul_add(a.x, b.y)),
+            x: self.x.mul_add(&a.x, &self.y.mul_add(&-a.y, &b.x)),
+            y: self.x.mul_add(&a.y, &self.y.mul_add(&a.x, &b.y)),
         }
     }
 }
 
-impl Mul<f64> for Complex {
-    type Output = Complex;
-    fn mul(self, rhs: f64) -> Self::Output {
+impl<T: Float> Mul<T> for Complex<T> {
+    type Output = Complex<T>;
+    fn mul(self, rhs: T) -> Self::Output {
         Complex {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -229,210 +389,40 @@ impl Mul<f64> for Complex {
     }
 }
 
-impl MulAssign<f64> for Complex {
-    fn mul_assign(&mut self, rhs: f64) {
+impl<T: Float> MulAssign<T> for Complex<T> {
+    fn mul_assign(&mut self, rhs: T) {
         self.x *= rhs;
         self.y *= rhs;
     }
 }
 
-impl MulAdd<f64, Complex> for Complex {
-    fn mul_add(self, a: &f64, b: &Self) -> Self {
+impl<T: Float> MulAdd<T, Complex<T>> for Complex<T> {
+    fn mul_add(self, a: &T, b: &Self) -> Self {
         Complex {
-            x: self.x.mul_add(*a, b.x),
-            y: self.y.mul_add(*a, b.y),
+            x: self.x.mul_add(a, &b.x),
+            y: self.y.mul_add(a, &b.y),
         }
     }
 }
 
-impl Div for Complex {
+impl<T: Float> Div for Complex<T> {
     type Output = Self;
     fn div(self, rhs: Self) -> Self {
         Complex {
-            x: (self.x * rhs.x + self.y * rhs.y)
-                / (rhs.x.powi(2) + rhs.y.powi(2)),
-            y: (self.y * rhs.x - self.x * rhs.y)
-                / (rhs.x.powi(2) + rhs.y.powi(2)),
+            x: (self.x * rhs.x + self.y * rhs.y) / rhs.norm_sqr(),
+            y: (self.y * rhs.x - self.x * rhs.y) / rhs.norm_sqr(),
         }
     }
 }
 
-impl DivAssign<&Complex> for Complex {
-    fn div_assign(&mut self, rhs: &Complex) {
-        let x =
-            (self.x * rhs.x + self.y * rhs.y) / (rhs.x.powi(2) + rhs.y.powi(2));
-        self.y =
-            (self.y * rhs.x - self.x * rhs.y) / (rhs.x.powi(2) + rhs.y.powi(2));
-        self.x = x;
+impl<T: Float> DivAssign<&Complex<T>> for Complex<T> {
+    fn div_assign(&mut self, rhs: &Complex<T>) {
+        *self = *self / *rhs;
     }
 }
 
-impl DivAssign for Complex {
+impl<T: Float> DivAssign for Complex<T> {
     fn div_assign(&mut self, rhs: Self) {
-        let x =
-            (self.x * rhs.x + self.y * rhs.y) / (rhs.x.powi(2) + rhs.y.powi(2));
-        self.y =
-            (self.y * rhs.x - self.x * rhs.y) / (rhs.x.powi(2) + rhs.y.powi(2));
-        self.x = x;
-    }
-}
-
-impl Dot<Complex> for Vector<Complex> {
-    fn dot(&self, v: &Vector<Complex>) -> Complex {
-        assert_eq!(v.size(), self.size(), "vectors must be the same size");
-
-        self * v
-    }
-}
-
-impl Mul<&Vector<Complex>> for &Vector<Complex> {
-    type Output = Complex;
-
-    fn mul(self, rhs: &Vector<Complex>) -> Self::Output {
-        self * &rhs._d
-    }
-}
-
-impl Mul<&Vec<Complex>> for &Vector<Complex> {
-    type Output = Complex;
-
-    fn mul(self, rhs: &Vec<Complex>) -> Self::Output {
-        self * &rhs[..]
-    }
-}
-
-impl Conj for Complex {
-    fn conj(&self) -> Complex {
-        Complex {
-            x: self.x,
-            y: -self.y,
-        }
-    }
-}
-
-impl Mul<&[Complex]> for &Vector<Complex> {
-    type Output = Complex;
-
-    fn mul(self, rhs: &[Complex]) -> Self::Output {
-        let mut sum = Complex::default();
-
-        for (a, b) in self._d.iter().zip(rhs) {
-            sum = a.mul_add(&b.conj(), &sum);
-        }
-
-        sum
-    }
-}
-
-impl Dot<Complex> for [Complex] {
-    fn dot(&self, v: &Vector<Complex>) -> Complex {
-        assert_eq!(v.size(), self.len(), "vectors must be the same size");
-
-        self * v
-    }
-}
-
-impl Mul<&Vector<Complex>> for &[Complex] {
-    type Output = Complex;
-
-    fn mul(self, rhs: &Vector<Complex>) -> Self::Output {
-        let mut sum = Complex::default();
-
-        for (a, b) in self.iter().zip(rhs._d.iter()) {
-            sum = a.mul_add(&b.conj(), &sum);
-        }
-
-        sum
-    }
-}
-impl MulAdd<Complex, Vector<Complex>> for Vector<Complex> {
-    fn mul_add(self, a: &Complex, b: &Vector<Complex>) -> Self {
-        assert!(self.size() == b.size(), "vectors must be the same size");
-
-        let mut vec = Vec::with_capacity(self.size());
-
-        for i in 0..self.size() {
-            vec.push(self[i].mul_add(a, &b[i]))
-        }
-
-        V!(vec)
-    }
-}
-
-impl MulAdd<f64, Vector<Complex>> for Vector<Complex> {
-    fn mul_add(self, a: &f64, b: &Vector<Complex>) -> Self {
-        assert!(self.size() == b.size(), "vectors must be the same size");
-
-        let mut vec = Vec::with_capacity(self.size());
-
-        for i in 0..self.size() {
-            vec.push(self[i].mul_add(a, &b[i]))
-        }
-
-        V!(vec)
-    }
-}
-
-impl Angle for Vector<Complex> {
-    type Output = f64;
-    fn angle_cos(u: &Vector<Complex>, v: &Vector<Complex>) -> Self::Output {
-        u.dot(v).x / (u.norm() * v.norm())
-    }
-}
-
-impl Transpose<Complex> for Matrix<Complex> {
-    fn transpose(&self) -> Matrix<Complex> {
-        let mut vec = Vec::with_capacity(self.rows * self.cols);
-        for i in 0..self.cols {
-            for j in 0..self.rows {
-                vec.push(self[j][i].conj());
-            }
-        }
-
-        Matrix {
-            rows: self.cols,
-            cols: self.rows,
-            _d: vec,
-        }
-    }
-}
-
-impl Lerp for Vector<Complex> {
-    fn lerp(u: Self, v: Self, t: f32) -> Self {
-        match t {
-            0. => u,
-            1. => v,
-            p => {
-                let mut vec = Vec::with_capacity(u.size());
-
-                for i in 0..u.size() {
-                    vec.push((v[i] - u[i]).mul_add(&(p as f64), &u[i]))
-                }
-
-                V!(vec)
-            }
-        }
-    }
-}
-
-impl Lerp for Matrix<Complex> {
-    fn lerp(u: Self, v: Self, t: f32) -> Self {
-        match t {
-            0. => u,
-            1. => v,
-            p => {
-                let mut vec = Vec::with_capacity(u._d.len());
-
-                for i in 0..u._d.len() {
-                    vec.push((v._d[i] - u._d[i]).mul_add(&(p as f64), &u._d[i]))
-                }
-
-                Matrix {
-                    _d: vec,
-                    cols: u.cols,
-                    rows: u.rows,
-                }
-            }
-        }
+        *self = *self / rhs;
     }
 }
@@ -0,0 +1,354 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::{
+    matrix::Layout,
+    scalar::{ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring, Sqrt},
+    Matrix, Vector,
+};
+
+// dual number x + dx ε with ε² = 0, arithmetic on it carries the derivative
+// along with the value (forward-mode automatic differentiation)
+#[derive(Copy, Clone, Default)]
+pub struct Dual<T = f64> {
+    pub x: T,
+    pub dx: T,
+}
+
+impl<T: Float> Dual<T> {
+    pub fn new(x: T, dx: T) -> Self {
+        Dual { x, dx }
+    }
+
+    pub fn constant(x: T) -> Self {
+        Dual {
+            x,
+            dx: T::default(),
+        }
+    }
+
+    pub fn variable(x: T) -> Self {
+        Dual { x, dx: T::one() }
+    }
+
+    // derivative chain rule: f(x + dx ε) = f(x) + f'(x) dx ε
+    fn chain(self, v: T, d: T) -> Self {
+        Dual {
+            x: v,
+            dx: d * self.dx,
+        }
+    }
+}
+
+impl<T: Float> Debug for Dual<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} + {}ε)", self.x, self.dx)
+    }
+}
+
+impl<T: Float> Display for Dual<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} + {}ε)", self.x, self.dx)
+    }
+}
+
+impl<T: Float> From<T> for Dual<T> {
+    fn from(value: T) -> Self {
+        Dual::constant(value)
+    }
+}
+
+// equality and ordering only look at the value, so pivoting and `abs`
+// branch exactly as they would on plain numbers
+impl<T: Float> PartialEq for Dual<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x
+    }
+}
+
+impl<T: Float> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.x.partial_cmp(&other.x)
+    }
+}
+
+impl<T: Float> Semiring for Dual<T> {
+    fn one() -> Self {
+        Dual::constant(T::one())
+    }
+
+    fn is_non_zero(&self) -> bool {
+        self.x.is_non_zero()
+    }
+}
+
+impl<T: Float> Ring for Dual<T> {}
+
+impl<T: Float> Field for Dual<T> {
+    fn inv(self) -> Self {
+        self.chain(self.x.inv(), -(self.x * self.x).inv())
+    }
+}
+
+impl<T: Float> ComplexField for Dual<T> {
+    type AbsOutput = Dual<T>;
+    type TanOutput = Dual<T>;
+    type SinOutput = Dual<T>;
+    type CosOutput = Dual<T>;
+
+    fn abs(&self) -> Self::AbsOutput {
+        if self.x < T::default() {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    fn tan(self) -> Self::TanOutput {
+        let c = self.x.cos();
+        self.chain(self.x.tan(), (c * c).inv())
+    }
+
+    fn sin(self) -> Self::SinOutput {
+        self.chain(self.x.sin(), self.x.cos())
+    }
+
+    fn cos(self) -> Self::CosOutput {
+        self.chain(self.x.cos(), -self.x.sin())
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(self.x.sinh(), self.x.cosh())
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(self.x.cosh(), self.x.sinh())
+    }
+
+    fn asin(self) -> Self {
+        let d = (T::one() - self.x * self.x).sqrt().inv();
+        self.chain(self.x.asin(), d)
+    }
+
+    fn acos(self) -> Self {
+        let d = (T::one() - self.x * self.x).sqrt().inv();
+        self.chain(self.x.acos(), -d)
+    }
+
+    fn atan(self) -> Self {
+        self.chain(self.x.atan(), (T::one() + self.x * self.x).inv())
+    }
+
+    fn exp(self) -> Self {
+        let e = self.x.exp();
+        self.chain(e, e)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.x.ln(), self.x.inv())
+    }
+
+    // d(x^n) = x^n (n' ln x + n x' / x), the power rule is used when the
+    // exponent is constant so that x = 0 stays finite
+    fn powf(self, n: Self) -> Self {
+        let v = self.x.powf(n.x);
+        if !n.dx.is_non_zero() {
+            let one = T::one();
+            return self.chain(v, n.x * self.x.powf(n.x - one));
+        }
+
+        Dual {
+            x: v,
+            dx: v * (n.dx * self.x.ln() + n.x * self.dx / self.x),
+        }
+    }
+
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+
+    fn hypot(self, other: Self) -> Self::AbsOutput {
+        let h = self.x.hypot(other.x);
+        if !h.is_non_zero() {
+            return Dual::constant(h);
+        }
+
+        Dual {
+            x: h,
+            dx: (self.x * self.dx + other.x * other.dx) / h,
+        }
+    }
+
+    fn real(self) -> Self::AbsOutput {
+        self
+    }
+}
+
+impl<T: Float> Float for Dual<T> {
+    fn atan2(self, other: Self) -> Self {
+        let r = self.x * self.x + other.x * other.x;
+        if !r.is_non_zero() {
+            return Dual::constant(self.x.atan2(other.x));
+        }
+
+        Dual {
+            x: self.x.atan2(other.x),
+            dx: (other.x * self.dx - self.x * other.dx) / r,
+        }
+    }
+
+    fn from_f32(v: f32) -> Self {
+        Dual::constant(T::from_f32(v))
+    }
+}
+
+impl<T: Float> Sqrt for Dual<T> {
+    fn sqrt(self) -> Self {
+        let s = self.x.sqrt();
+        if !s.is_non_zero() {
+            return Dual::constant(s);
+        }
+
+        self.chain(s, (s + s).inv())
+    }
+}
+
+impl<T: Float> Lerp for Dual<T> {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => (v - u) * Dual::from_f32(p) + u,
+        }
+    }
+}
+
+impl<T: Float> MulAdd<Dual<T>, Dual<T>> for Dual<T> {
+    fn mul_add(self, a: &Self, b: &Self) -> Self {
+        self * *a + *b
+    }
+}
+
+impl<T: Float> Sum for Dual<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Dual::default(), |a, b| a + b)
+    }
+}
+
+impl<T: Float> Neg for Dual<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Dual {
+            x: -self.x,
+            dx: -self.dx,
+        }
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Dual {
+            x: self.x + rhs.x,
+            dx: self.dx + rhs.dx,
+        }
+    }
+}
+
+impl<T: Float> Sub for Dual<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Dual {
+            x: self.x - rhs.x,
+            dx: self.dx - rhs.dx,
+        }
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Dual {
+            x: self.x * rhs.x,
+            dx: self.dx * rhs.x + self.x * rhs.dx,
+        }
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Dual {
+            x: self.x / rhs.x,
+            dx: (self.dx * rhs.x - self.x * rhs.dx) / (rhs.x * rhs.x),
+        }
+    }
+}
+
+macro_rules! assign {
+    ($tr:ident, $f:ident, $op:tt) => {
+        impl<T: Float> $tr for Dual<T> {
+            fn $f(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+assign!(AddAssign, add_assign, +);
+assign!(SubAssign, sub_assign, -);
+assign!(MulAssign, mul_assign, *);
+assign!(DivAssign, div_assign, /);
+
+pub fn derivative<T: Float, F: Fn(Dual<T>) -> Dual<T>>(f: F, x: T) -> T {
+    f(Dual::variable(x)).dx
+}
+
+// seeds `x` along the `j`-th basis direction
+fn seed<T: Float>(x: &Vector<T>, j: usize) -> Vector<Dual<T>> {
+    let mut ret = x.map(Dual::constant);
+    ret[j].dx = T::one();
+    ret
+}
+
+pub fn gradient<T: Float, F: Fn(&Vector<Dual<T>>) -> Dual<T>>(
+    f: F,
+    x: &Vector<T>,
+) -> Vector<T> {
+    let vec: Vec<T> = (0..x.size()).map(|j| f(&seed(x, j)).dx).collect();
+    Vector::from(vec)
+}
+
+// one evaluation of `f` per input direction, column `j` of the result holds
+// the derivatives of every output with respect to `x[j]`
+pub fn jacobian<T: Float, F: Fn(&Vector<Dual<T>>) -> Vector<Dual<T>>>(
+    f: F,
+    x: &Vector<T>,
+) -> Matrix<T> {
+    let n = x.size();
+    let mut vec = vec![];
+    let mut rows = 0;
+
+    for j in 0..n {
+        let y = f(&seed(x, j));
+        if j == 0 {
+            rows = y.size();
+        }
+        assert_eq!(y.size(), rows, "function output size must not change");
+        vec.extend(y.iter().map(|v| v.dx));
+    }
+
+    Matrix {
+        _d: vec,
+        rows,
+        cols: n,
+        layout: Layout::ColMajor,
+    }
+    .into_layout(Layout::RowMajor)
+}
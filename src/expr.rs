@@ -0,0 +1,257 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{
+    matrix::Layout,
+    scalar::{Lerp, Ring, Semiring},
+    Matrix,
+};
+
+// a lazily evaluated element-wise expression over matrices of the same
+// shape, `at` returns the element at `index` in row-major order
+pub trait Expr: Sized {
+    type Elem: Ring;
+
+    fn shape(&self) -> (usize, usize);
+    fn at(&self, index: usize) -> Self::Elem;
+
+    fn eval(self) -> Matrix<Self::Elem> {
+        let (rows, cols) = self.shape();
+        let mut vec = Vec::with_capacity(rows * cols);
+        for i in 0..rows * cols {
+            vec.push(self.at(i));
+        }
+
+        Matrix {
+            _d: vec,
+            rows,
+            cols,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    fn lerp<R: Expr<Elem = Self::Elem>>(
+        self,
+        other: R,
+        t: f32,
+    ) -> LerpExpr<Self, R> {
+        assert_eq!(
+            self.shape(),
+            other.shape(),
+            "matrices must be the same size"
+        );
+
+        LerpExpr(self, other, t)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Lazy<'a, K>(&'a Matrix<K>);
+
+#[derive(Clone, Copy)]
+pub struct AddExpr<A, B>(A, B);
+
+#[derive(Clone, Copy)]
+pub struct SubExpr<A, B>(A, B);
+
+#[derive(Clone, Copy)]
+pub struct NegExpr<A>(A);
+
+#[derive(Clone, Copy)]
+pub struct ScaleExpr<A, U>(A, U);
+
+#[derive(Clone, Copy)]
+pub struct LerpExpr<A, B>(A, B, f32);
+
+impl<K: Ring> Matrix<K> {
+    pub fn lazy(&self) -> Lazy<'_, K> {
+        Lazy(self)
+    }
+
+    pub fn assign<E: Expr<Elem = K>>(&mut self, expr: E) {
+        assert_eq!(
+            self.shape(),
+            expr.shape(),
+            "matrices must be the same size"
+        );
+
+        match self.layout {
+            Layout::RowMajor => {
+                for i in 0..self._d.len() {
+                    self._d[i] = expr.at(i);
+                }
+            }
+            Layout::ColMajor => {
+                for j in 0..self.cols {
+                    for i in 0..self.rows {
+                        self._d[j * self.rows + i] = expr.at(i * self.cols + j);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<K: Ring> Expr for Lazy<'_, K> {
+    type Elem = K;
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn at(&self, index: usize) -> K {
+        let mat = self.0;
+        match mat.layout {
+            Layout::RowMajor => mat._d[index].clone(),
+            Layout::ColMajor => {
+                let (i, j) = (index / mat.cols, index % mat.cols);
+                mat._d[j * mat.rows + i].clone()
+            }
+        }
+    }
+}
+
+impl<K: Ring> Expr for &Matrix<K> {
+    type Elem = K;
+
+    fn shape(&self) -> (usize, usize) {
+        Matrix::shape(self)
+    }
+
+    fn at(&self, index: usize) -> K {
+        Lazy(*self).at(index)
+    }
+}
+
+impl<A: Expr, B: Expr<Elem = A::Elem>> Expr for AddExpr<A, B> {
+    type Elem = A::Elem;
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn at(&self, index: usize) -> A::Elem {
+        self.0.at(index) + self.1.at(index)
+    }
+}
+
+impl<A: Expr, B: Expr<Elem = A::Elem>> Expr for SubExpr<A, B> {
+    type Elem = A::Elem;
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn at(&self, index: usize) -> A::Elem {
+        self.0.at(index) - self.1.at(index)
+    }
+}
+
+impl<A: Expr> Expr for NegExpr<A> {
+    type Elem = A::Elem;
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn at(&self, index: usize) -> A::Elem {
+        -self.0.at(index)
+    }
+}
+
+impl<A: Expr, U: Semiring> Expr for ScaleExpr<A, U>
+where
+    A::Elem: Mul<U, Output = A::Elem>,
+{
+    type Elem = A::Elem;
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn at(&self, index: usize) -> A::Elem {
+        self.0.at(index) * self.1.clone()
+    }
+}
+
+impl<A: Expr, B: Expr<Elem = A::Elem>> Expr for LerpExpr<A, B>
+where
+    A::Elem: Lerp,
+{
+    type Elem = A::Elem;
+
+    fn shape(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+
+    fn at(&self, index: usize) -> A::Elem {
+        A::Elem::lerp_elem(self.0.at(index), self.1.at(index), self.2)
+    }
+}
+
+macro_rules! ops {
+    ($ty:ty, [$($gen:tt)*]) => {
+        impl<$($gen)*, R: Expr<Elem = <$ty as Expr>::Elem>> Add<R> for $ty
+        where
+            $ty: Expr,
+        {
+            type Output = AddExpr<Self, R>;
+
+            fn add(self, rhs: R) -> Self::Output {
+                assert_eq!(
+                    self.shape(),
+                    rhs.shape(),
+                    "matrices must be the same size"
+                );
+
+                AddExpr(self, rhs)
+            }
+        }
+
+        impl<$($gen)*, R: Expr<Elem = <$ty as Expr>::Elem>> Sub<R> for $ty
+        where
+            $ty: Expr,
+        {
+            type Output = SubExpr<Self, R>;
+
+            fn sub(self, rhs: R) -> Self::Output {
+                assert_eq!(
+                    self.shape(),
+                    rhs.shape(),
+                    "matrices must be the same size"
+                );
+
+                SubExpr(self, rhs)
+            }
+        }
+
+        impl<$($gen)*> Neg for $ty
+        where
+            $ty: Expr,
+        {
+            type Output = NegExpr<Self>;
+
+            fn neg(self) -> Self::Output {
+                NegExpr(self)
+            }
+        }
+
+        impl<$($gen)*, U: Semiring> Mul<U> for $ty
+        where
+            $ty: Expr,
+            <$ty as Expr>::Elem: Mul<U, Output = <$ty as Expr>::Elem>,
+        {
+            type Output = ScaleExpr<Self, U>;
+
+            fn mul(self, rhs: U) -> Self::Output {
+                ScaleExpr(self, rhs)
+            }
+        }
+    };
+}
+
+ops!(Lazy<'a, K>, ['a, K: Ring]);
+ops!(AddExpr<A, B>, [A, B]);
+ops!(SubExpr<A, B>, [A, B]);
+ops!(NegExpr<A>, [A]);
+ops!(ScaleExpr<A, V>, [A, V]);
+ops!(LerpExpr<A, B>, [A, B]);
@@ -1,14 +1,50 @@
-use std::ops::Mul;
-
 use crate::{
-    matrix::Transpose,
-    scalar::{Lerp, MulAdd, Sqrt},
+    scalar::{
+        Cast, ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring, Sqrt,
+    },
+    simd,
     utils::EPSILON,
-    vector::Angle,
-    Dot, Matrix, Scalar, Vector, V,
 };
 
-impl Scalar for f32 {
+impl Semiring for f32 {
+    fn one() -> Self {
+        1.
+    }
+
+    fn is_non_zero(&self) -> bool {
+        self.abs() > EPSILON as f32
+    }
+
+    fn slice_dot(u: &[f32], v: &[f32]) -> f32 {
+        simd::dot_f32(u, v)
+    }
+
+    fn slice_mul_sum(u: &[f32], v: &[f32]) -> f32 {
+        simd::dot_f32(u, v)
+    }
+
+    fn slice_axpy(y: &mut [f32], a: f32, x: &[f32]) {
+        simd::axpy_f32(y, a, x)
+    }
+
+    fn slice_scale(x: &mut [f32], a: f32) {
+        simd::scale_f32(x, a)
+    }
+
+    fn slice_add(y: &mut [f32], x: &[f32]) {
+        simd::add_f32(y, x)
+    }
+}
+
+impl Ring for f32 {}
+
+impl Field for f32 {
+    fn inv(self) -> Self {
+        1. / self
+    }
+}
+
+impl ComplexField for f32 {
     type AbsOutput = f32;
     type SinOutput = f32;
     type CosOutput = f32;
@@ -17,18 +53,10 @@ impl Scalar for f32 {
         if *self < 0. {
             -self
         } else {
-            self.clone()
+            *self
         }
     }
 
-    fn one() -> Self {
-        1.
-    }
-
-    fn inv(self) -> Self {
-        1. / self
-    }
-
     type TanOutput = f32;
     fn tan(self) -> Self::TanOutput {
         f32::tan(self)
@@ -42,169 +70,99 @@ impl Scalar for f32 {
         f32::sin(self)
     }
 
-    fn is_non_zero(&self) -> bool {
-        self.abs() > EPSILON as f32
+    fn sinh(self) -> Self {
+        f32::sinh(self)
     }
-}
 
-impl MulAdd<f32, f32> for f32 {
-    fn mul_add(self, a: &f32, b: &f32) -> Self {
-        self.mul_add(*a, *b)
+    fn cosh(self) -> Self {
+        f32::cosh(self)
     }
-}
 
-impl Sqrt for f32 {
-    fn sqrt(self: Self) -> Self {
-        self.powf(0.5)
+    fn asin(self) -> Self {
+        f32::asin(self)
     }
-}
 
-impl Lerp for f32 {
-    fn lerp(u: Self, v: Self, t: f32) -> Self {
-        match t {
-            0. => u,
-            1. => v,
-            p => u + (v - u) * p,
-        }
+    fn acos(self) -> Self {
+        f32::acos(self)
     }
-}
-
-impl Dot<f32> for [f32] {
-    fn dot(&self, v: &Vector<f32>) -> f32 {
-        assert_eq!(v.size(), self.len(), "vectors must be the same size");
 
-        self * v
+    fn atan(self) -> Self {
+        f32::atan(self)
     }
-}
-
-impl Dot<f32> for Vector<f32> {
-    fn dot(&self, v: &Vector<f32>) -> f32 {
-        assert_eq!(v.size(), self.size(), "vectors must be the same size");
 
-        self * v
+    fn exp(self) -> Self {
+        f32::exp(self)
     }
-}
 
-impl Mul<&Vector<f32>> for &Vector<f32> {
-    type Output = f32;
-
-    fn mul(self, rhs: &Vector<f32>) -> Self::Output {
-        self * &rhs._d
+    fn ln(self) -> Self {
+        f32::ln(self)
     }
-}
 
-impl Mul<&Vec<f32>> for &Vector<f32> {
-    type Output = f32;
+    fn powf(self, n: Self::AbsOutput) -> Self {
+        f32::powf(self, n)
+    }
 
-    fn mul(self, rhs: &Vec<f32>) -> Self::Output {
-        self * &rhs[..]
+    fn powc(self, n: Self) -> Self {
+        f32::powf(self, n)
     }
-}
 
-impl Mul<&Vector<f32>> for &Vec<f32> {
-    type Output = f32;
+    fn hypot(self, other: Self) -> Self::AbsOutput {
+        f32::hypot(self, other)
+    }
 
-    fn mul(self, rhs: &Vector<f32>) -> Self::Output {
-        rhs * self
+    fn real(self) -> Self::AbsOutput {
+        self
     }
 }
 
-impl Mul<&[f32]> for &Vector<f32> {
-    type Output = f32;
-
-    fn mul(self, rhs: &[f32]) -> Self::Output {
-        let mut sum = f32::default();
-
-        for (a, &b) in self._d.iter().zip(rhs) {
-            sum = a.mul_add(b, sum);
-        }
+impl Float for f32 {
+    fn atan2(self, other: Self) -> Self {
+        f32::atan2(self, other)
+    }
 
-        sum
+    fn from_f32(v: f32) -> Self {
+        v
     }
 }
 
-impl Mul<&Vector<f32>> for &[f32] {
-    type Output = f32;
-
-    fn mul(self, rhs: &Vector<f32>) -> Self::Output {
-        rhs * self
+impl MulAdd<f32, f32> for f32 {
+    fn mul_add(self, a: &f32, b: &f32) -> Self {
+        self.mul_add(*a, *b)
     }
 }
 
-impl MulAdd<f32, Vector<f32>> for Vector<f32> {
-    fn mul_add(self, a: &f32, b: &Vector<f32>) -> Self {
-        assert!(self.size() == b.size(), "vectors must be the same size");
-
-        let mut vec = Vec::with_capacity(self.size());
-
-        for i in 0..self.size() {
-            vec.push(self[i].mul_add(*a, b[i]))
-        }
-
-        V!(vec)
+impl Sqrt for f32 {
+    fn sqrt(self) -> Self {
+        self.powf(0.5)
     }
 }
 
-impl Angle for Vector<f32> {
-    type Output = f32;
-    fn angle_cos(u: &Vector<f32>, v: &Vector<f32>) -> Self::Output {
-        u.dot(v) / (u.norm() * v.norm())
+impl Cast<f32> for f32 {
+    fn cast(self) -> f32 {
+        self
     }
 }
 
-impl Transpose<f32> for Matrix<f32> {
-    fn transpose(&self) -> Matrix<f32> {
-        let mut vec = Vec::with_capacity(self.rows * self.cols);
-        for i in 0..self.cols {
-            for j in 0..self.rows {
-                vec.push(self[j][i]);
-            }
-        }
-
-        Matrix {
-            rows: self.cols,
-            cols: self.rows,
-            _d: vec,
-        }
+impl Cast<f64> for f32 {
+    fn cast(self) -> f64 {
+        self as f64
     }
 }
 
-impl Lerp for Vector<f32> {
+impl Lerp for f32 {
     fn lerp(u: Self, v: Self, t: f32) -> Self {
         match t {
             0. => u,
             1. => v,
-            p => {
-                let mut vec = Vec::with_capacity(u.size());
-
-                for i in 0..u.size() {
-                    vec.push((v[i] - u[i]).mul_add(p, u[i]))
-                }
-
-                V!(vec)
-            }
+            p => u + (v - u) * p,
         }
     }
-}
 
-impl Lerp for Matrix<f32> {
-    fn lerp(u: Self, v: Self, t: f32) -> Self {
+    fn lerp_elem(u: Self, v: Self, t: f32) -> Self {
         match t {
             0. => u,
             1. => v,
-            p => {
-                let mut vec = Vec::with_capacity(u._d.len());
-
-                for i in 0..u._d.len() {
-                    vec.push((v._d[i] - u._d[i]).mul_add(p, u._d[i]))
-                }
-
-                Matrix {
-                    _d: vec,
-                    cols: u.cols,
-                    rows: u.rows,
-                }
-            }
+            p => (v - u).mul_add(p, u),
         }
     }
 }
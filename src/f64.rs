@@ -1,31 +1,59 @@
-use std::ops::Mul;
-
 use crate::{
-    matrix::Transpose,
-    scalar::{Lerp, MulAdd, Sqrt},
+    scalar::{
+        Cast, ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring, Sqrt,
+    },
+    simd,
     utils::EPSILON,
-    vector::Angle,
-    Dot, Matrix, Scalar, Vector, V,
 };
 
-impl Scalar for f64 {
-    type AbsOutput = f64;
+impl Semiring for f64 {
+    fn one() -> Self {
+        1.
+    }
 
-    fn abs(&self) -> Self::AbsOutput {
-        if *self < 0. {
-            -self
-        } else {
-            self.clone()
-        }
+    fn is_non_zero(&self) -> bool {
+        self.abs() > EPSILON
     }
 
-    fn one() -> Self {
-        1.
+    fn slice_dot(u: &[f64], v: &[f64]) -> f64 {
+        simd::dot_f64(u, v)
     }
 
+    fn slice_mul_sum(u: &[f64], v: &[f64]) -> f64 {
+        simd::dot_f64(u, v)
+    }
+
+    fn slice_axpy(y: &mut [f64], a: f64, x: &[f64]) {
+        simd::axpy_f64(y, a, x)
+    }
+
+    fn slice_scale(x: &mut [f64], a: f64) {
+        simd::scale_f64(x, a)
+    }
+
+    fn slice_add(y: &mut [f64], x: &[f64]) {
+        simd::add_f64(y, x)
+    }
+}
+
+impl Ring for f64 {}
+
+impl Field for f64 {
     fn inv(self) -> Self {
         1. / self
     }
+}
+
+impl ComplexField for f64 {
+    type AbsOutput = f64;
+
+    fn abs(&self) -> Self::AbsOutput {
+        if *self < 0. {
+            -self
+        } else {
+            *self
+        }
+    }
 
     type TanOutput = f64;
     fn tan(self) -> Self::TanOutput {
@@ -34,7 +62,7 @@ impl Scalar for f64 {
 
     type CosOutput = f64;
     fn cos(self) -> Self::CosOutput {
-        f64::sin(self)
+        f64::cos(self)
     }
 
     type SinOutput = f64;
@@ -42,169 +70,99 @@ impl Scalar for f64 {
         f64::sin(self)
     }
 
-    fn is_non_zero(&self) -> bool {
-        self.abs() > EPSILON
+    fn sinh(self) -> Self {
+        f64::sinh(self)
     }
-}
 
-impl MulAdd<f64, f64> for f64 {
-    fn mul_add(self, a: &f64, b: &f64) -> Self {
-        self.mul_add(*a, *b)
+    fn cosh(self) -> Self {
+        f64::cosh(self)
     }
-}
 
-impl Sqrt for f64 {
-    fn sqrt(self: Self) -> Self {
-        self.powf(0.5)
+    fn asin(self) -> Self {
+        f64::asin(self)
     }
-}
 
-impl Lerp for f64 {
-    fn lerp(u: Self, v: Self, t: f32) -> Self {
-        match t {
-            0. => u,
-            1. => v,
-            p => u + (v - u) * p as f64,
-        }
+    fn acos(self) -> Self {
+        f64::acos(self)
     }
-}
-
-impl Dot<f64> for [f64] {
-    fn dot(&self, v: &Vector<f64>) -> f64 {
-        assert_eq!(v.size(), self.len(), "vectors must be the same size");
 
-        self * v
+    fn atan(self) -> Self {
+        f64::atan(self)
     }
-}
-
-impl Dot<f64> for Vector<f64> {
-    fn dot(&self, v: &Vector<f64>) -> f64 {
-        assert_eq!(v.size(), self.size(), "vectors must be the same size");
 
-        self * v
+    fn exp(self) -> Self {
+        f64::exp(self)
     }
-}
 
-impl Mul<&Vector<f64>> for &Vector<f64> {
-    type Output = f64;
-
-    fn mul(self, rhs: &Vector<f64>) -> Self::Output {
-        self * &rhs._d
+    fn ln(self) -> Self {
+        f64::ln(self)
     }
-}
 
-impl Mul<&Vec<f64>> for &Vector<f64> {
-    type Output = f64;
+    fn powf(self, n: Self::AbsOutput) -> Self {
+        f64::powf(self, n)
+    }
 
-    fn mul(self, rhs: &Vec<f64>) -> Self::Output {
-        self * &rhs[..]
+    fn powc(self, n: Self) -> Self {
+        f64::powf(self, n)
     }
-}
 
-impl Mul<&Vector<f64>> for &Vec<f64> {
-    type Output = f64;
+    fn hypot(self, other: Self) -> Self::AbsOutput {
+        f64::hypot(self, other)
+    }
 
-    fn mul(self, rhs: &Vector<f64>) -> Self::Output {
-        rhs * self
+    fn real(self) -> Self::AbsOutput {
+        self
     }
 }
 
-impl Mul<&[f64]> for &Vector<f64> {
-    type Output = f64;
-
-    fn mul(self, rhs: &[f64]) -> Self::Output {
-        let mut sum = f64::default();
-
-        for (a, &b) in self._d.iter().zip(rhs) {
-            sum = a.mul_add(b, sum);
-        }
+impl Float for f64 {
+    fn atan2(self, other: Self) -> Self {
+        f64::atan2(self, other)
+    }
 
-        sum
+    fn from_f32(v: f32) -> Self {
+        v as f64
     }
 }
 
-impl Mul<&Vector<f64>> for &[f64] {
-    type Output = f64;
-
-    fn mul(self, rhs: &Vector<f64>) -> Self::Output {
-        rhs * self
+impl MulAdd<f64, f64> for f64 {
+    fn mul_add(self, a: &f64, b: &f64) -> Self {
+        self.mul_add(*a, *b)
     }
 }
 
-impl MulAdd<f64, Vector<f64>> for Vector<f64> {
-    fn mul_add(self, a: &f64, b: &Vector<f64>) -> Self {
-        assert!(self.size() == b.size(), "vectors must be the same size");
-
-        let mut vec = Vec::with_capacity(self.size());
-
-        for i in 0..self.size() {
-            vec.push(self[i].mul_add(*a, b[i]))
-        }
-
-        V!(vec)
+impl Sqrt for f64 {
+    fn sqrt(self) -> Self {
+        self.powf(0.5)
     }
 }
 
-impl Angle for Vector<f64> {
-    type Output = f64;
-    fn angle_cos(u: &Vector<f64>, v: &Vector<f64>) -> Self::Output {
-        u.dot(v) / (u.norm() * v.norm())
+impl Cast<f64> for f64 {
+    fn cast(self) -> f64 {
+        self
     }
 }
 
-impl Transpose<f64> for Matrix<f64> {
-    fn transpose(&self) -> Matrix<f64> {
-        let mut vec = Vec::with_capacity(self.rows * self.cols);
-        for i in 0..self.cols {
-            for j in 0..self.rows {
-                vec.push(self[j][i]);
-            }
-        }
-
-        Matrix {
-            rows: self.cols,
-            cols: self.rows,
-            _d: vec,
-        }
+impl Cast<f32> for f64 {
+    fn cast(self) -> f32 {
+        self as f32
     }
 }
 
-impl Lerp for Vector<f64> {
+impl Lerp for f64 {
     fn lerp(u: Self, v: Self, t: f32) -> Self {
         match t {
             0. => u,
             1. => v,
-            p => {
-                let mut vec = Vec::with_capacity(u.size());
-
-                for i in 0..u.size() {
-                    vec.push((v[i] - u[i]).mul_add(p as f64, u[i]))
-                }
-
-                V!(vec)
-            }
+            p => u + (v - u) * p as f64,
         }
     }
-}
 
-impl Lerp for Matrix<f64> {
-    fn lerp(u: Self, v: Self, t: f32) -> Self {
+    fn lerp_elem(u: Self, v: Self, t: f32) -> Self {
         match t {
             0. => u,
             1. => v,
-            p => {
-                let mut vec = Vec::with_capacity(u._d.len());
-
-                for i in 0..u._d.len() {
-                    vec.push((v._d[i] - u._d[i]).mul_add(p as f64, u._d[i]))
-                }
-
-                Matrix {
-                    _d: vec,
-                    cols: u.cols,
-                    rows: u.rows,
-                }
-            }
+            p => (v - u).mul_add(p as f64, u),
         }
     }
 }
@@ -0,0 +1,446 @@
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::scalar::{
+    Cast, ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring, Sqrt,
+};
+
+// signed Q(31 - FRAC).FRAC fixed point number stored in an i32, results that
+// leave the range saturate, or wrap around when WRAP is set
+//
+// everything is computed on integers, so results are bit-identical on every
+// platform, only `from_f32` and the `Display` / `Debug` impls touch floats
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const FRAC: u32, const WRAP: bool = false>(i32);
+
+pub type Q16_16 = Fixed<16>;
+pub type Q1_31 = Fixed<31>;
+
+// the transcendental functions work on Q31.32 values in an i64
+const ONE: i64 = 1 << 32;
+const PI: i64 = 13493037705;
+const HALF_PI: i64 = 6746518852;
+const TWO_PI: i64 = 26986075409;
+const LN_2: i64 = 2977044472;
+
+// atan(2^-i), and the gain of the rotations they drive
+const ATAN: [i64; 32] = [
+    3373259426, 1991351318, 1052175346, 534100635, 268086748, 134174063,
+    67103403, 33553749, 16777131, 8388597, 4194303, 2097152, 1048576, 524288,
+    262144, 131072, 65536, 32768, 16384, 8192, 4096, 2048, 1024, 512, 256, 128,
+    64, 32, 16, 8, 4, 2,
+];
+const CORDIC_GAIN: i64 = 2608131496;
+
+// CORDIC rotation mode, (sin a, cos a)
+fn sin_cos(a: i64) -> (i64, i64) {
+    let mut a = a.rem_euclid(TWO_PI);
+    if a > PI {
+        a -= TWO_PI;
+    }
+
+    // the rotations only converge on [-π/2, π/2]
+    let mut flip = false;
+    if a > HALF_PI {
+        (a, flip) = (PI - a, true);
+    } else if a < -HALF_PI {
+        (a, flip) = (-PI - a, true);
+    }
+
+    let (mut x, mut y, mut z) = (CORDIC_GAIN, 0, a);
+    for (i, t) in ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            (x, y, z) = (x - dx, y + dy, z - t);
+        } else {
+            (x, y, z) = (x + dx, y - dy, z + t);
+        }
+    }
+    (y, if flip { -x } else { x })
+}
+
+// CORDIC vectoring mode, `y` and `x` only need to share a scale
+fn atan2(mut y: i64, mut x: i64) -> i64 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    // the top bit at 2^59 keeps the precision without overflowing the gain
+    let s = x.unsigned_abs().max(y.unsigned_abs()).leading_zeros() as i32 - 4;
+    if s > 0 {
+        (x, y) = (x << s, y << s);
+    } else {
+        (x, y) = (x >> -s, y >> -s);
+    }
+
+    let mut z = 0;
+    if x < 0 {
+        z = if y >= 0 { PI } else { -PI };
+        (x, y) = (-x, -y);
+    }
+    for (i, t) in ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            (x, y, z) = (x + dx, y - dy, z + t);
+        } else {
+            (x, y, z) = (x - dx, y + dy, z - t);
+        }
+    }
+    z
+}
+
+// e^x = 2^k e^r with |r| <= ln 2 / 2, the Taylor series of e^r converges
+// quickly
+fn exp(x: i64) -> i128 {
+    let k = (x + LN_2 / 2).div_euclid(LN_2);
+    if k > 64 {
+        return i128::MAX >> 1;
+    }
+    if k < -64 {
+        return 0;
+    }
+
+    let r = (x - k * LN_2) as i128;
+    let (mut sum, mut term) = (ONE as i128, ONE as i128);
+    for n in 1..16 {
+        term = ((term * r) >> 32) / n;
+        sum += term;
+    }
+
+    if k >= 0 {
+        sum << k
+    } else {
+        sum >> -k
+    }
+}
+
+// ln x = k ln 2 + 2 atanh((m - 1) / (m + 1)) for x = 2^k m, m in [1, 2)
+fn ln(x: i64) -> i64 {
+    let k = 31 - x.leading_zeros() as i64;
+    let m = (if k >= 0 { x >> k } else { x << -k }) as i128;
+
+    let z = ((m - ONE as i128) << 32) / (m + ONE as i128);
+    let z2 = (z * z) >> 32;
+    let (mut sum, mut term) = (0, z);
+    for n in (1..24).step_by(2) {
+        sum += term / n;
+        term = (term * z2) >> 32;
+    }
+    2 * sum as i64 + k * LN_2
+}
+
+// √(1 - x²), x is clamped to [-1, 1]
+fn cos_of_sin(x: i64) -> i64 {
+    let x = x.clamp(-ONE, ONE) as i128;
+    (((ONE as i128) * (ONE as i128) - x * x) as u128).isqrt() as i64
+}
+
+impl<const FRAC: u32, const WRAP: bool> Fixed<FRAC, WRAP> {
+    const FRAC_BITS: u32 = {
+        assert!(FRAC >= 1 && FRAC <= 31, "fraction bits must be in 1..=31");
+        FRAC
+    };
+
+    pub const MIN: Self = Fixed(i32::MIN);
+    pub const MAX: Self = Fixed(i32::MAX);
+
+    pub fn from_bits(bits: i32) -> Self {
+        Fixed(bits)
+    }
+
+    pub fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    pub fn from_int(n: i32) -> Self {
+        Self::narrow((n as i128) << Self::FRAC_BITS)
+    }
+
+    // nearest value, ties away from zero
+    pub fn from_f32(v: f32) -> Self {
+        let raw = (v as f64 * (1u64 << Self::FRAC_BITS) as f64).round();
+        Self::narrow(raw as i128)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << Self::FRAC_BITS) as f64
+    }
+
+    pub fn frac(self) -> Self {
+        Fixed(self.0 & ((1i64 << Self::FRAC_BITS) - 1) as i32)
+    }
+
+    // brings an out of range result back according to the overflow mode
+    fn narrow(v: i128) -> Self {
+        if WRAP {
+            Fixed(v as i32)
+        } else {
+            Fixed(v.clamp(i32::MIN as i128, i32::MAX as i128) as i32)
+        }
+    }
+
+    fn to_q32(self) -> i64 {
+        (self.0 as i64) << (32 - Self::FRAC_BITS)
+    }
+
+    fn from_q32(v: i128) -> Self {
+        let s = 32 - Self::FRAC_BITS;
+        Self::narrow((v + (1 << (s - 1))) >> s)
+    }
+
+    // q = n / d rounded half away from zero, d must not be zero
+    fn div_round(n: i128, d: i128) -> i128 {
+        let q = 2 * n / d;
+        (q + q.signum()) / 2
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Debug for Fixed<FRAC, WRAP> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_f64())
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Display for Fixed<FRAC, WRAP> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> From<i32> for Fixed<FRAC, WRAP> {
+    fn from(value: i32) -> Self {
+        Fixed::from_int(value)
+    }
+}
+
+// Q1.31 can't represent one, it becomes the largest value below
+impl<const FRAC: u32, const WRAP: bool> Semiring for Fixed<FRAC, WRAP> {
+    fn one() -> Self {
+        Fixed((1i64 << Self::FRAC_BITS).min(i32::MAX as i64) as i32)
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Ring for Fixed<FRAC, WRAP> {}
+
+impl<const FRAC: u32, const WRAP: bool> Field for Fixed<FRAC, WRAP> {
+    fn inv(self) -> Self {
+        Self::one() / self
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> ComplexField for Fixed<FRAC, WRAP> {
+    type AbsOutput = Self;
+    type TanOutput = Self;
+    type SinOutput = Self;
+    type CosOutput = Self;
+
+    fn abs(&self) -> Self::AbsOutput {
+        Self::narrow((self.0 as i128).abs())
+    }
+
+    // tangent at the poles saturates
+    fn tan(self) -> Self::TanOutput {
+        let (s, c) = sin_cos(self.to_q32());
+        match c {
+            0 => Self::narrow(s.signum() as i128 * i128::MAX),
+            c => Self::from_q32(Self::div_round((s as i128) << 32, c as i128)),
+        }
+    }
+
+    fn sin(self) -> Self::SinOutput {
+        Self::from_q32(sin_cos(self.to_q32()).0 as i128)
+    }
+
+    fn cos(self) -> Self::CosOutput {
+        Self::from_q32(sin_cos(self.to_q32()).1 as i128)
+    }
+
+    fn sinh(self) -> Self {
+        let x = self.to_q32();
+        Self::from_q32((exp(x) - exp(-x)) / 2)
+    }
+
+    fn cosh(self) -> Self {
+        let x = self.to_q32();
+        Self::from_q32((exp(x) + exp(-x)) / 2)
+    }
+
+    fn asin(self) -> Self {
+        let x = self.to_q32().clamp(-ONE, ONE);
+        Self::from_q32(atan2(x, cos_of_sin(x)) as i128)
+    }
+
+    fn acos(self) -> Self {
+        let x = self.to_q32().clamp(-ONE, ONE);
+        Self::from_q32(atan2(cos_of_sin(x), x) as i128)
+    }
+
+    fn atan(self) -> Self {
+        Self::from_q32(atan2(self.to_q32(), ONE) as i128)
+    }
+
+    fn exp(self) -> Self {
+        Self::from_q32(exp(self.to_q32()))
+    }
+
+    // the logarithm of zero or less is taken as the lowest value
+    fn ln(self) -> Self {
+        if self.0 <= 0 {
+            return Self::MIN;
+        }
+        Self::from_q32(ln(self.to_q32()) as i128)
+    }
+
+    // e^(n ln |x|), a negative base keeps its sign for odd integer powers
+    // and has no real value, taken as zero, for fractional ones. Negative
+    // powers of zero go through `inv` so they saturate or panic like it
+    fn powf(self, n: Self::AbsOutput) -> Self {
+        if self.0 == 0 {
+            return match n.0 {
+                0 => Self::one(),
+                1.. => self,
+                _ => self.inv(),
+            };
+        }
+        let int = n.frac().0 == 0;
+        if self.0 < 0 && !int {
+            return Fixed(0);
+        }
+
+        let l = ln(self.abs().to_q32().max(1)) as i128;
+        let p = exp(((n.to_q32() as i128 * l) >> 32) as i64);
+        let odd = (n.0 >> Self::FRAC_BITS) & 1 == 1;
+        Self::from_q32(if self.0 < 0 && odd { -p } else { p })
+    }
+
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+
+    fn hypot(self, other: Self) -> Self::AbsOutput {
+        let (a, b) = (self.0 as i128, other.0 as i128);
+        Self::narrow((a * a + b * b).unsigned_abs().isqrt() as i128)
+    }
+
+    fn real(self) -> Self::AbsOutput {
+        self
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Float for Fixed<FRAC, WRAP> {
+    fn atan2(self, other: Self) -> Self {
+        Self::from_q32(atan2(self.to_q32(), other.to_q32()) as i128)
+    }
+
+    fn from_f32(v: f32) -> Self {
+        Fixed::from_f32(v)
+    }
+}
+
+// rounded down, the root of a negative number is taken as zero
+impl<const FRAC: u32, const WRAP: bool> Sqrt for Fixed<FRAC, WRAP> {
+    fn sqrt(self) -> Self {
+        let v = (self.0.max(0) as u64) << Self::FRAC_BITS;
+        Fixed(v.isqrt() as i32)
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Cast<Fixed<FRAC, WRAP>> for f32 {
+    fn cast(self) -> Fixed<FRAC, WRAP> {
+        Fixed::from_f32(self)
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Cast<f64> for Fixed<FRAC, WRAP> {
+    fn cast(self) -> f64 {
+        self.to_f64()
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Lerp for Fixed<FRAC, WRAP> {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => u + (v - u) * Fixed::from_f32(p),
+        }
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> MulAdd<Self, Self>
+    for Fixed<FRAC, WRAP>
+{
+    fn mul_add(self, a: &Self, b: &Self) -> Self {
+        self * *a + *b
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Sum for Fixed<FRAC, WRAP> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Fixed(0), |a, b| a + b)
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Neg for Fixed<FRAC, WRAP> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::narrow(-(self.0 as i128))
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Add for Fixed<FRAC, WRAP> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::narrow(self.0 as i128 + rhs.0 as i128)
+    }
+}
+
+impl<const FRAC: u32, const WRAP: bool> Sub for Fixed<FRAC, WRAP> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::narrow(self.0 as i128 - rhs.0 as i128)
+    }
+}
+
+// the product is rounded to nearest, ties up
+impl<const FRAC: u32, const WRAP: bool> Mul for Fixed<FRAC, WRAP> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let p = self.0 as i128 * rhs.0 as i128;
+        Self::narrow((p + (1 << (Self::FRAC_BITS - 1))) >> Self::FRAC_BITS)
+    }
+}
+
+// dividing by zero saturates to the side of the dividend, or panics when
+// wrapping like integer division does
+impl<const FRAC: u32, const WRAP: bool> Div for Fixed<FRAC, WRAP> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            assert!(!WRAP, "division by zero");
+            return Self::narrow(self.0.signum() as i128 * i128::MAX);
+        }
+        let n = (self.0 as i128) << Self::FRAC_BITS;
+        Self::narrow(Self::div_round(n, rhs.0 as i128))
+    }
+}
+
+macro_rules! assign {
+    ($tr:ident, $f:ident, $op:tt) => {
+        impl<const FRAC: u32, const WRAP: bool> $tr for Fixed<FRAC, WRAP> {
+            fn $f(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+assign!(AddAssign, add_assign, +);
+assign!(SubAssign, sub_assign, -);
+assign!(MulAssign, mul_assign, *);
+assign!(DivAssign, div_assign, /);
@@ -0,0 +1,341 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::{
+    matrix::Layout,
+    scalar::{Field, Ring, Semiring},
+    Matrix,
+};
+
+// element of the two element field, addition is xor and multiplication is
+// and
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Gf2(pub bool);
+
+impl From<bool> for Gf2 {
+    fn from(value: bool) -> Self {
+        Gf2(value)
+    }
+}
+
+impl From<u8> for Gf2 {
+    fn from(value: u8) -> Self {
+        Gf2(value & 1 == 1)
+    }
+}
+
+impl Debug for Gf2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 as u8)
+    }
+}
+
+impl Display for Gf2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 as u8)
+    }
+}
+
+impl Semiring for Gf2 {
+    fn one() -> Self {
+        Gf2(true)
+    }
+
+    const EXACT: bool = true;
+}
+
+impl Ring for Gf2 {}
+
+impl Field for Gf2 {
+    fn inv(self) -> Self {
+        assert!(self.0, "division by zero");
+        self
+    }
+}
+
+impl Neg for Gf2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+macro_rules! ops {
+    ($tr:ident, $f:ident, $atr:ident, $af:ident, $op:tt) => {
+        impl $tr for Gf2 {
+            type Output = Self;
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn $f(self, rhs: Self) -> Self {
+                Gf2(self.0 $op rhs.0)
+            }
+        }
+
+        impl $atr for Gf2 {
+            fn $af(&mut self, rhs: Self) {
+                *self = $tr::$f(*self, rhs);
+            }
+        }
+    };
+}
+
+ops!(Add, add, AddAssign, add_assign, ^);
+ops!(Sub, sub, SubAssign, sub_assign, ^);
+ops!(Mul, mul, MulAssign, mul_assign, &);
+
+impl Div for Gf2 {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for Gf2 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+// GF(2) matrix packed 64 entries per word, each row starts on a word
+// boundary so row operations are word-wide xors
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitMatrix {
+    pub(crate) words: Vec<u64>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Debug for BitMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("[")?;
+        for i in 0..self.rows {
+            let row: Vec<u8> =
+                (0..self.cols).map(|j| self.get(i, j) as u8).collect();
+            write!(f, "{:?}", row)?;
+            if i != self.rows - 1 {
+                writeln!(f, ",")?;
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+impl From<&Matrix<Gf2>> for BitMatrix {
+    fn from(mat: &Matrix<Gf2>) -> Self {
+        let mut ret = BitMatrix::new(mat.rows, mat.cols);
+        for ((i, j), v) in mat.enumerate_indexed() {
+            ret.set(i, j, v.0);
+        }
+        ret
+    }
+}
+
+impl BitMatrix {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        BitMatrix {
+            words: vec![0; rows * cols.div_ceil(64)],
+            rows,
+            cols,
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut ret = BitMatrix::new(n, n);
+        for i in 0..n {
+            ret.set(i, i, true);
+        }
+        ret
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    fn stride(&self) -> usize {
+        self.cols.div_ceil(64)
+    }
+
+    fn row(&self, i: usize) -> &[u64] {
+        let stride = self.stride();
+        &self.words[i * stride..(i + 1) * stride]
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+
+        self.row(i)[j / 64] >> (j % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, v: bool) {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+
+        let w = i * self.stride() + j / 64;
+        if v {
+            self.words[w] |= 1 << (j % 64);
+        } else {
+            self.words[w] &= !(1 << (j % 64));
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<Gf2> {
+        let mut vec = Vec::with_capacity(self.rows * self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                vec.push(Gf2(self.get(i, j)));
+            }
+        }
+
+        Matrix {
+            _d: vec,
+            rows: self.rows,
+            cols: self.cols,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    // row `dst` ^= row `src`, starting at word `from`
+    fn xor_row(&mut self, dst: usize, src: usize, from: usize) {
+        let stride = self.stride();
+        for w in from..stride {
+            let x = self.words[src * stride + w];
+            self.words[dst * stride + w] ^= x;
+        }
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let stride = self.stride();
+        for w in 0..stride {
+            self.words.swap(a * stride + w, b * stride + w);
+        }
+    }
+
+    // reduces to reduced row echelon form over the first `cols` columns and
+    // returns the pivot column of every non-zero row
+    fn eliminate(&mut self, cols: usize) -> Vec<usize> {
+        let mut pivots = vec![];
+
+        for c in 0..cols {
+            let r = pivots.len();
+            if r == self.rows {
+                break;
+            }
+            let Some(p) = (r..self.rows).find(|&i| self.get(i, c)) else {
+                continue;
+            };
+            if p != r {
+                self.swap_rows(p, r);
+            }
+            for i in 0..self.rows {
+                if i != r && self.get(i, c) {
+                    self.xor_row(i, r, c / 64);
+                }
+            }
+            pivots.push(c);
+        }
+
+        pivots
+    }
+
+    pub fn row_echelon(&self) -> BitMatrix {
+        let mut ret = self.clone();
+        ret.row_echelon_mut();
+        ret
+    }
+
+    pub fn row_echelon_mut(&mut self) -> &mut Self {
+        self.eliminate(self.cols);
+        self
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().eliminate(self.cols).len()
+    }
+
+    pub fn determinant(&self) -> Gf2 {
+        assert!(self.is_square(), "matrix must be squared");
+
+        Gf2(self.rank() == self.rows)
+    }
+
+    pub fn inverse(&self) -> Result<BitMatrix, &'static str> {
+        assert!(self.is_square(), "matrix must be squared");
+
+        let n = self.rows;
+        let mut aug = BitMatrix::new(n, n * 2);
+        for i in 0..n {
+            for j in 0..n {
+                aug.set(i, j, self.get(i, j));
+            }
+            aug.set(i, n + i, true);
+        }
+
+        if aug.eliminate(n).len() != n {
+            return Err("matrix is singular");
+        }
+
+        let mut ret = BitMatrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                ret.set(i, j, aug.get(i, n + j));
+            }
+        }
+        Ok(ret)
+    }
+
+    // basis of { x | A x = 0 }, one vector per row
+    pub fn null_space(&self) -> BitMatrix {
+        let mut mat = self.clone();
+        let pivots = mat.eliminate(self.cols);
+        let free: Vec<usize> =
+            (0..self.cols).filter(|j| !pivots.contains(j)).collect();
+
+        let mut ret = BitMatrix::new(free.len(), self.cols);
+        for (k, &f) in free.iter().enumerate() {
+            ret.set(k, f, true);
+            for (r, &p) in pivots.iter().enumerate() {
+                if mat.get(r, f) {
+                    ret.set(k, p, true);
+                }
+            }
+        }
+        ret
+    }
+
+    pub fn mul_mat(&self, mat: &BitMatrix) -> BitMatrix {
+        assert_eq!(
+            self.cols, mat.rows,
+            "bad input for matrix and matrix multiplication"
+        );
+
+        let mut ret = BitMatrix::new(self.rows, mat.cols);
+        let stride = ret.stride();
+        for i in 0..self.rows {
+            for k in (0..self.cols).filter(|&k| self.get(i, k)) {
+                for (w, x) in mat.row(k).iter().enumerate() {
+                    ret.words[i * stride + w] ^= x;
+                }
+            }
+        }
+        ret
+    }
+
+    pub fn transpose(&self) -> BitMatrix {
+        let mut ret = BitMatrix::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                if self.get(i, j) {
+                    ret.set(j, i, true);
+                }
+            }
+        }
+        ret
+    }
+}
@@ -0,0 +1,334 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::scalar::{
+    Cast, ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring, Sqrt,
+};
+
+// rounds away the low `shift` bits of `m` to nearest, ties to even
+fn round_bits(m: u32, shift: u32) -> u32 {
+    let (r, rem, half) = (m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1));
+    if rem > half || (rem == half && r & 1 == 1) {
+        r + 1
+    } else {
+        r
+    }
+}
+
+// IEEE binary16: 1 sign, 5 exponent and 10 mantissa bits
+fn f32_to_f16(x: f32) -> u16 {
+    let b = x.to_bits();
+    let sign = (b >> 16) as u16 & 0x8000;
+    let (exp, man) = ((b >> 23) as i32 & 0xff, b & 0x7f_ffff);
+
+    if exp == 0xff {
+        let nan = if man != 0 {
+            0x200 | (man >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+
+    let e = exp - 127 + 15;
+    if e >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if e <= 0 {
+        // subnormal, in units of 2^-24
+        let shift = (14 - e) as u32;
+        if shift > 24 {
+            return sign;
+        }
+        return sign | round_bits(man | 0x80_0000, shift) as u16;
+    }
+
+    // a carry out of the mantissa bumps the exponent, up to infinity
+    sign | round_bits(((e as u32) << 23) | man, 13) as u16
+}
+
+fn f16_to_f32(h: u16) -> f32 {
+    let sign = ((h & 0x8000) as u32) << 16;
+    let (exp, man) = ((h >> 10) as u32 & 0x1f, (h & 0x3ff) as u32);
+
+    let bits = match exp {
+        0x1f => sign | 0x7f80_0000 | (man << 13),
+        0 if man == 0 => sign,
+        0 => {
+            let v = man as f32 / (1 << 24) as f32;
+            return if sign != 0 { -v } else { v };
+        }
+        _ => sign | ((exp + 112) << 23) | (man << 13),
+    };
+    f32::from_bits(bits)
+}
+
+// bfloat16 is the upper half of an f32
+fn f32_to_bf16(x: f32) -> u16 {
+    let b = x.to_bits();
+    if x.is_nan() {
+        return (b >> 16) as u16 | 0x40;
+    }
+    round_bits(b, 16) as u16
+}
+
+fn bf16_to_f32(h: u16) -> f32 {
+    f32::from_bits((h as u32) << 16)
+}
+
+// 16 bit storage types, arithmetic widens to f32 and rounds the result back
+// once, sums and products of slices accumulate in f32 before that rounding
+macro_rules! half {
+    ($t:ident, $to:ident, $from:ident) => {
+        #[derive(Copy, Clone, Default)]
+        pub struct $t(u16);
+
+        impl $t {
+            pub fn from_bits(bits: u16) -> Self {
+                $t(bits)
+            }
+
+            pub fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            pub fn from_f32(x: f32) -> Self {
+                $t($to(x))
+            }
+
+            pub fn to_f32(self) -> f32 {
+                $from(self.0)
+            }
+
+            pub fn is_nan(self) -> bool {
+                self.to_f32().is_nan()
+            }
+
+            fn map(self, f: fn(f32) -> f32) -> Self {
+                $t::from_f32(f(self.to_f32()))
+            }
+        }
+
+        impl From<f32> for $t {
+            fn from(value: f32) -> Self {
+                $t::from_f32(value)
+            }
+        }
+
+        impl From<$t> for f32 {
+            fn from(value: $t) -> Self {
+                value.to_f32()
+            }
+        }
+
+        impl Debug for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{:?}", self.to_f32())
+            }
+        }
+
+        impl Display for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.to_f32())
+            }
+        }
+
+        // compares values, so 0 == -0 and NaN != NaN
+        impl PartialEq for $t {
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $t {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl Semiring for $t {
+            fn one() -> Self {
+                $t::from_f32(1.)
+            }
+
+            fn is_non_zero(&self) -> bool {
+                self.to_f32().is_non_zero()
+            }
+
+            fn slice_dot(u: &[$t], v: &[$t]) -> $t {
+                $t::slice_mul_sum(u, v)
+            }
+
+            fn slice_mul_sum(u: &[$t], v: &[$t]) -> $t {
+                let mut sum = 0f32;
+                for (a, b) in u.iter().zip(v) {
+                    sum = a.to_f32().mul_add(b.to_f32(), sum);
+                }
+                $t::from_f32(sum)
+            }
+        }
+
+        impl Ring for $t {}
+
+        impl Field for $t {
+            fn inv(self) -> Self {
+                self.map(|x| 1. / x)
+            }
+        }
+
+        impl ComplexField for $t {
+            type AbsOutput = $t;
+            type TanOutput = $t;
+            type SinOutput = $t;
+            type CosOutput = $t;
+
+            fn abs(&self) -> Self::AbsOutput {
+                $t(self.0 & 0x7fff)
+            }
+
+            fn tan(self) -> Self::TanOutput {
+                self.map(f32::tan)
+            }
+
+            fn sin(self) -> Self::SinOutput {
+                self.map(f32::sin)
+            }
+
+            fn cos(self) -> Self::CosOutput {
+                self.map(f32::cos)
+            }
+
+            fn sinh(self) -> Self {
+                self.map(f32::sinh)
+            }
+
+            fn cosh(self) -> Self {
+                self.map(f32::cosh)
+            }
+
+            fn asin(self) -> Self {
+                self.map(f32::asin)
+            }
+
+            fn acos(self) -> Self {
+                self.map(f32::acos)
+            }
+
+            fn atan(self) -> Self {
+                self.map(f32::atan)
+            }
+
+            fn exp(self) -> Self {
+                self.map(f32::exp)
+            }
+
+            fn ln(self) -> Self {
+                self.map(f32::ln)
+            }
+
+            fn powf(self, n: Self::AbsOutput) -> Self {
+                $t::from_f32(self.to_f32().powf(n.to_f32()))
+            }
+
+            fn powc(self, n: Self) -> Self {
+                self.powf(n)
+            }
+
+            fn hypot(self, other: Self) -> Self::AbsOutput {
+                $t::from_f32(self.to_f32().hypot(other.to_f32()))
+            }
+
+            fn real(self) -> Self::AbsOutput {
+                self
+            }
+        }
+
+        impl Float for $t {
+            fn atan2(self, other: Self) -> Self {
+                $t::from_f32(self.to_f32().atan2(other.to_f32()))
+            }
+
+            fn from_f32(v: f32) -> Self {
+                $t::from_f32(v)
+            }
+        }
+
+        impl Sqrt for $t {
+            fn sqrt(self) -> Self {
+                self.map(f32::sqrt)
+            }
+        }
+
+        impl Cast<$t> for f32 {
+            fn cast(self) -> $t {
+                $t::from_f32(self)
+            }
+        }
+
+        impl Cast<f32> for $t {
+            fn cast(self) -> f32 {
+                self.to_f32()
+            }
+        }
+
+        impl Cast<f64> for $t {
+            fn cast(self) -> f64 {
+                self.to_f32() as f64
+            }
+        }
+
+        impl Lerp for $t {
+            fn lerp(u: Self, v: Self, t: f32) -> Self {
+                $t::from_f32(<f32 as Lerp>::lerp(u.to_f32(), v.to_f32(), t))
+            }
+        }
+
+        // one rounding for the whole fused operation
+        impl MulAdd<$t, $t> for $t {
+            fn mul_add(self, a: &$t, b: &$t) -> Self {
+                $t::from_f32(self.to_f32().mul_add(a.to_f32(), b.to_f32()))
+            }
+        }
+
+        impl Sum for $t {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                $t::from_f32(iter.map($t::to_f32).sum())
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+            fn neg(self) -> Self {
+                $t(self.0 ^ 0x8000)
+            }
+        }
+
+        half!(@op $t, Add, add, AddAssign, add_assign, +);
+        half!(@op $t, Sub, sub, SubAssign, sub_assign, -);
+        half!(@op $t, Mul, mul, MulAssign, mul_assign, *);
+        half!(@op $t, Div, div, DivAssign, div_assign, /);
+    };
+    (@op $t:ident, $tr:ident, $f:ident, $tra:ident, $fa:ident, $op:tt) => {
+        impl $tr for $t {
+            type Output = Self;
+            fn $f(self, rhs: Self) -> Self {
+                $t::from_f32(self.to_f32() $op rhs.to_f32())
+            }
+        }
+
+        impl $tra for $t {
+            fn $fa(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+half!(F16, f32_to_f16, f16_to_f32);
+half!(Bf16, f32_to_bf16, bf16_to_f32);
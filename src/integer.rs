@@ -0,0 +1,264 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::{
+    matrix::Layout,
+    scalar::{Ring, Semiring},
+    Matrix,
+};
+
+// signed integers usable as matrix elements and as the numerator and
+// denominator of a `Rational`, arithmetic is checked so fixed width types
+// can report overflow
+pub trait Int: Clone + Debug + Display + Default + Ord + Hash {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+    fn checked_rem(&self, rhs: &Self) -> Option<Self>;
+    fn checked_neg(&self) -> Option<Self>;
+    fn wrapping_add(&self, rhs: &Self) -> Self;
+    fn wrapping_mul(&self, rhs: &Self) -> Self;
+    fn div_euclid(&self, rhs: &Self) -> Self;
+    fn rem_euclid(&self, rhs: &Self) -> Self;
+    fn isqrt(&self) -> Self;
+    fn to_f64(&self) -> f64;
+    fn from_f64(v: f64) -> Option<Self>;
+}
+
+macro_rules! int {
+    ($t:ty) => {
+        impl Int for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *rhs)
+            }
+
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *rhs)
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *rhs)
+            }
+
+            fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_div(*self, *rhs)
+            }
+
+            fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_rem(*self, *rhs)
+            }
+
+            fn checked_neg(&self) -> Option<Self> {
+                <$t>::checked_neg(*self)
+            }
+
+            fn wrapping_add(&self, rhs: &Self) -> Self {
+                <$t>::wrapping_add(*self, *rhs)
+            }
+
+            fn wrapping_mul(&self, rhs: &Self) -> Self {
+                <$t>::wrapping_mul(*self, *rhs)
+            }
+
+            fn div_euclid(&self, rhs: &Self) -> Self {
+                <$t>::div_euclid(*self, *rhs)
+            }
+
+            fn rem_euclid(&self, rhs: &Self) -> Self {
+                <$t>::rem_euclid(*self, *rhs)
+            }
+
+            fn isqrt(&self) -> Self {
+                <$t>::isqrt(*self)
+            }
+
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+
+            fn from_f64(v: f64) -> Option<Self> {
+                let bound = -(<$t>::MIN as f64);
+                if v >= -bound && v < bound {
+                    Some(v as $t)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl Semiring for $t {
+            fn one() -> Self {
+                1
+            }
+
+            const EXACT: bool = true;
+        }
+
+        impl Ring for $t {}
+    };
+}
+
+int!(i64);
+int!(i128);
+
+impl<T: Int> Matrix<T> {
+    // exact determinant by Bareiss fraction-free elimination, `None` when an
+    // intermediate value overflows
+    pub fn checked_determinant(&self) -> Option<T> {
+        assert!(self.rows == self.cols, "matrix must be squared");
+
+        let mut mat = self.to_layout(Layout::RowMajor);
+        let rank = mat.bareiss(true)?;
+        if rank < self.rows {
+            return Some(T::zero());
+        }
+        Some(mat._d.last().cloned().unwrap_or_else(T::one))
+    }
+
+    pub fn checked_rank(&self) -> Option<usize> {
+        self.to_layout(Layout::RowMajor).bareiss(false)
+    }
+
+    pub fn checked_mul_mat(&self, mat: &Matrix<T>) -> Option<Matrix<T>> {
+        assert_eq!(
+            self.cols, mat.rows,
+            "bad input for matrix and matrix multiplication"
+        );
+
+        let (lhs, rhs) = (
+            self.to_layout(Layout::RowMajor),
+            mat.to_layout(Layout::RowMajor),
+        );
+        let mut vec = Vec::with_capacity(self.rows * mat.cols);
+        for i in 0..self.rows {
+            for j in 0..mat.cols {
+                let mut val = T::zero();
+                for r in 0..self.cols {
+                    val =
+                        val.checked_add(&lhs[i][r].checked_mul(&rhs[r][j])?)?;
+                }
+                vec.push(val);
+            }
+        }
+
+        Some(Matrix::new(self.rows, mat.cols, vec))
+    }
+
+    // two's complement arithmetic modulo 2^bits, the result is exact
+    // whenever it fits
+    pub fn wrapping_mul_mat(&self, mat: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            self.cols, mat.rows,
+            "bad input for matrix and matrix multiplication"
+        );
+
+        let (lhs, rhs) = (
+            self.to_layout(Layout::RowMajor),
+            mat.to_layout(Layout::RowMajor),
+        );
+        let mut vec = Vec::with_capacity(self.rows * mat.cols);
+        for i in 0..self.rows {
+            for j in 0..mat.cols {
+                let mut val = T::zero();
+                for r in 0..self.cols {
+                    val = val.wrapping_add(&lhs[i][r].wrapping_mul(&rhs[r][j]));
+                }
+                vec.push(val);
+            }
+        }
+
+        Matrix::new(self.rows, mat.cols, vec)
+    }
+
+    // adj(A)[i][j] = (-1)^(i + j) det(A without row j and column i), also
+    // defined for singular matrices
+    pub fn checked_adjugate(&self) -> Option<Matrix<T>> {
+        assert!(self.rows == self.cols, "matrix must be squared");
+
+        let n = self.rows;
+        if n == 1 {
+            return Some(Matrix::new(1, 1, vec![T::one()]));
+        }
+
+        let mat = self.to_layout(Layout::RowMajor);
+        let mut vec = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                let mut minor = Vec::with_capacity((n - 1) * (n - 1));
+                for r in (0..n).filter(|&r| r != j) {
+                    for c in (0..n).filter(|&c| c != i) {
+                        minor.push(mat[r][c].clone());
+                    }
+                }
+                let det =
+                    Matrix::new(n - 1, n - 1, minor).checked_determinant()?;
+                vec.push(if (i + j) % 2 == 1 {
+                    det.checked_neg()?
+                } else {
+                    det
+                });
+            }
+        }
+
+        Some(Matrix::new(n, n, vec))
+    }
+
+    // in place fraction-free row reduction, returns the rank; every
+    // division is exact so it never leaves the integers. With `square` the
+    // pivots must sit on the diagonal and the last one is the determinant,
+    // a row swap negates the rest of the row to keep the sign right
+    fn bareiss(&mut self, square: bool) -> Option<usize> {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut prev = T::one();
+        let mut r = 0;
+
+        for c in 0..cols {
+            if r == rows {
+                break;
+            }
+            let Some(p) = (r..rows).find(|&i| self[i][c] != T::zero()) else {
+                if square {
+                    return Some(r);
+                }
+                continue;
+            };
+            if p != r {
+                for j in 0..cols {
+                    self._d.swap(r * cols + j, p * cols + j);
+                }
+                if square {
+                    for j in c..cols {
+                        self[r][j] = self[r][j].checked_neg()?;
+                    }
+                }
+            }
+
+            for i in r + 1..rows {
+                for j in c + 1..cols {
+                    let v = self[i][j]
+                        .checked_mul(&self[r][c])?
+                        .checked_sub(&self[i][c].checked_mul(&self[r][j])?)?;
+                    self[i][j] = v.checked_div(&prev)?;
+                }
+                self[i][c] = T::zero();
+            }
+            prev = self[r][c].clone();
+            r += 1;
+        }
+
+        Some(r)
+    }
+}
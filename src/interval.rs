@@ -0,0 +1,713 @@
+use std::{
+    cmp::Ordering,
+    f64::consts::{FRAC_PI_2, PI, TAU},
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::{
+    matrix::Layout,
+    scalar::{Cast, ComplexField, Field, Lerp, MulAdd, Ring, Semiring, Sqrt},
+    Matrix, Vector,
+};
+
+// closed interval [lo, hi] of f64, every operation rounds its bounds
+// outward so the result encloses the exact result for any point of the
+// operands
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+// the libm functions are only faithful to a few ulps
+const LIBM_ULPS: usize = 4;
+
+fn down(mut x: f64, ulps: usize) -> f64 {
+    for _ in 0..ulps {
+        x = x.next_down();
+    }
+    x
+}
+
+fn up(mut x: f64, ulps: usize) -> f64 {
+    for _ in 0..ulps {
+        x = x.next_up();
+    }
+    x
+}
+
+// below this a product or quotient may have lost bits to underflow, so its
+// rounding error can no longer be recovered exactly
+const TINY: f64 = 1e-290;
+
+// results rounded to nearest together with their exact rounding error
+// (TwoSum and fma), the error is NaN when it can't be trusted
+fn add_err(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let t = s - a;
+    (s, (a - (s - t)) + (b - t))
+}
+
+// 0 * inf is taken as 0, the bound of a product with an exact zero
+fn mul_err(a: f64, b: f64) -> (f64, f64) {
+    if a == 0. || b == 0. {
+        return (0., 0.);
+    }
+    let p = a * b;
+    if p.abs() < TINY {
+        return (p, f64::NAN);
+    }
+    (p, a.mul_add(b, -p))
+}
+
+fn div_err(a: f64, b: f64) -> (f64, f64) {
+    if a == 0. {
+        return (0., 0.);
+    }
+    let q = a / b;
+    if !q.is_finite() || q.abs() < TINY || a.abs() < TINY {
+        return (q, f64::NAN);
+    }
+    (q, (-q).mul_add(b, a) * b.signum())
+}
+
+fn sqrt_err(a: f64) -> (f64, f64) {
+    let s = a.sqrt();
+    if a == 0. || a.is_infinite() {
+        return (s, 0.);
+    }
+    if a < TINY {
+        return (s, f64::NAN);
+    }
+    (s, (-s).mul_add(s, a))
+}
+
+// a rounded result only moves one ulp, and only when it was inexact
+fn lower((x, e): (f64, f64)) -> f64 {
+    if e >= 0. {
+        x
+    } else {
+        x.next_down()
+    }
+}
+
+fn upper((x, e): (f64, f64)) -> f64 {
+    if e <= 0. {
+        x
+    } else {
+        x.next_up()
+    }
+}
+
+fn min4(v: [f64; 4]) -> f64 {
+    v.iter().fold(f64::INFINITY, |a, &b| a.min(b))
+}
+
+fn max4(v: [f64; 4]) -> f64 {
+    v.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b))
+}
+
+// does [lo, hi] possibly contain phase + 2kπ for some k, the test errs on
+// the side of yes
+fn hits(lo: f64, hi: f64, phase: f64) -> bool {
+    let eps = 1e-9 * hi.abs().max(lo.abs()).max(1.);
+    let k = ((lo - eps - phase) / TAU).ceil();
+    phase + k * TAU <= hi + eps
+}
+
+impl Interval {
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "interval bounds must be ordered");
+        Interval { lo, hi }
+    }
+
+    pub fn point(x: f64) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    pub fn entire() -> Self {
+        Interval {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+        }
+    }
+
+    // the interval of a float given as a decimal literal, which is itself
+    // only the nearest double
+    pub fn around(x: f64) -> Self {
+        Interval {
+            lo: down(x, 1),
+            hi: up(x, 1),
+        }
+    }
+
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    pub fn mid(&self) -> f64 {
+        if self.lo.is_infinite() || self.hi.is_infinite() {
+            return if self.lo == -self.hi {
+                0.
+            } else {
+                self.lo + self.hi
+            };
+        }
+        self.lo / 2. + self.hi / 2.
+    }
+
+    pub fn width(&self) -> f64 {
+        up(self.hi - self.lo, 1)
+    }
+
+    // smallest absolute value over the interval
+    pub fn mig(&self) -> f64 {
+        if self.contains_zero() {
+            0.
+        } else {
+            self.lo.abs().min(self.hi.abs())
+        }
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.contains(0.)
+    }
+
+    pub fn is_subset_of(&self, other: &Interval) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    pub fn is_interior_of(&self, other: &Interval) -> bool {
+        other.lo < self.lo && self.hi < other.hi
+    }
+
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        (lo <= hi).then_some(Interval { lo, hi })
+    }
+
+    // bounds computed by libm
+    fn widen(lo: f64, hi: f64) -> Self {
+        Interval {
+            lo: down(lo, LIBM_ULPS),
+            hi: up(hi, LIBM_ULPS),
+        }
+    }
+
+    // image of a non-decreasing function
+    fn increasing(self, f: fn(f64) -> f64) -> Self {
+        Interval::widen(f(self.lo), f(self.hi))
+    }
+
+    fn sqr(self) -> Self {
+        let a = self.abs();
+        Interval {
+            lo: lower(mul_err(a.lo, a.lo)),
+            hi: upper(mul_err(a.hi, a.hi)),
+        }
+    }
+
+    fn powi(self, k: u64) -> Self {
+        if k == 0 {
+            return Interval::one();
+        }
+        if k % 2 == 1 {
+            return self * self.powi(k - 1);
+        }
+
+        // x^2m = |x|^2m, and products of non-negative intervals are tight
+        let (mut base, mut e, mut ret) = (self.abs(), k, Interval::one());
+        while e > 0 {
+            if e & 1 == 1 {
+                ret *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        ret
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(value: f64) -> Self {
+        Interval::point(value)
+    }
+}
+
+impl Debug for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+// `a < b` holds when every point of `a` is below every point of `b`,
+// overlapping intervals are unordered
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl Semiring for Interval {
+    fn one() -> Self {
+        Interval::point(1.)
+    }
+
+    // pivots must be certainly non-zero
+    fn is_non_zero(&self) -> bool {
+        !self.contains_zero()
+    }
+}
+
+impl Ring for Interval {}
+
+impl Field for Interval {
+    fn inv(self) -> Self {
+        Interval::one() / self
+    }
+}
+
+impl ComplexField for Interval {
+    type AbsOutput = Interval;
+    type TanOutput = Interval;
+    type SinOutput = Interval;
+    type CosOutput = Interval;
+
+    fn abs(&self) -> Self::AbsOutput {
+        if self.lo >= 0. {
+            *self
+        } else if self.hi <= 0. {
+            -*self
+        } else {
+            Interval {
+                lo: 0.,
+                hi: self.hi.max(-self.lo),
+            }
+        }
+    }
+
+    fn tan(self) -> Self::TanOutput {
+        if self.hi - self.lo >= PI
+            || hits(self.lo, self.hi, FRAC_PI_2)
+            || hits(self.lo, self.hi, -FRAC_PI_2)
+        {
+            return Interval::entire();
+        }
+        self.increasing(f64::tan)
+    }
+
+    fn sin(self) -> Self::SinOutput {
+        if self.hi - self.lo >= TAU {
+            return Interval::new(-1., 1.);
+        }
+
+        let (a, b) = (self.lo.sin(), self.hi.sin());
+        let mut ret = Interval::widen(a.min(b), a.max(b));
+        if hits(self.lo, self.hi, FRAC_PI_2) {
+            ret.hi = 1.;
+        }
+        if hits(self.lo, self.hi, -FRAC_PI_2) {
+            ret.lo = -1.;
+        }
+        // NaN bounds leave nothing to intersect, nothing is known then
+        ret.intersect(&Interval::new(-1., 1.))
+            .unwrap_or(Interval::entire())
+    }
+
+    fn cos(self) -> Self::CosOutput {
+        if self.hi - self.lo >= TAU {
+            return Interval::new(-1., 1.);
+        }
+
+        let (a, b) = (self.lo.cos(), self.hi.cos());
+        let mut ret = Interval::widen(a.min(b), a.max(b));
+        if hits(self.lo, self.hi, 0.) {
+            ret.hi = 1.;
+        }
+        if hits(self.lo, self.hi, PI) {
+            ret.lo = -1.;
+        }
+        // NaN bounds leave nothing to intersect, nothing is known then
+        ret.intersect(&Interval::new(-1., 1.))
+            .unwrap_or(Interval::entire())
+    }
+
+    fn sinh(self) -> Self {
+        self.increasing(f64::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        let a = self.abs();
+        let ret = a.increasing(f64::cosh);
+        Interval {
+            lo: ret.lo.max(1.),
+            hi: ret.hi,
+        }
+    }
+
+    fn asin(self) -> Self {
+        let x = Interval {
+            lo: self.lo.max(-1.),
+            hi: self.hi.min(1.),
+        };
+        x.increasing(f64::asin)
+    }
+
+    fn acos(self) -> Self {
+        let x = Interval {
+            lo: self.lo.max(-1.),
+            hi: self.hi.min(1.),
+        };
+        Interval::widen(x.hi.acos(), x.lo.acos())
+    }
+
+    fn atan(self) -> Self {
+        self.increasing(f64::atan)
+    }
+
+    fn exp(self) -> Self {
+        let ret = self.increasing(f64::exp);
+        Interval {
+            lo: ret.lo.max(0.),
+            hi: ret.hi,
+        }
+    }
+
+    fn ln(self) -> Self {
+        let x = Interval {
+            lo: self.lo.max(0.),
+            hi: self.hi,
+        };
+        x.increasing(f64::ln)
+    }
+
+    // exact integer exponents keep negative bases, any other exponent goes
+    // through exp(n ln x)
+    fn powf(self, n: Self) -> Self {
+        if n.lo == n.hi && n.lo.fract() == 0. && n.lo.abs() < 1e15 {
+            let k = n.lo.abs() as u64;
+            let p = self.powi(k);
+            return if n.lo < 0. { p.inv() } else { p };
+        }
+        (n * self.ln()).exp()
+    }
+
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+
+    fn hypot(self, other: Self) -> Self::AbsOutput {
+        (self.sqr() + other.sqr()).sqrt()
+    }
+
+    fn real(self) -> Self::AbsOutput {
+        self
+    }
+}
+
+impl Sqrt for Interval {
+    fn sqrt(self) -> Self {
+        Interval {
+            lo: lower(sqrt_err(self.lo.max(0.))).max(0.),
+            hi: upper(sqrt_err(self.hi)),
+        }
+    }
+}
+
+impl Cast<Interval> for f64 {
+    fn cast(self) -> Interval {
+        Interval::point(self)
+    }
+}
+
+impl Cast<f64> for Interval {
+    fn cast(self) -> f64 {
+        self.mid()
+    }
+}
+
+impl Lerp for Interval {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => (v - u) * Interval::point(p as f64) + u,
+        }
+    }
+}
+
+impl MulAdd<Interval, Interval> for Interval {
+    fn mul_add(self, a: &Self, b: &Self) -> Self {
+        self * *a + *b
+    }
+}
+
+impl Sum for Interval {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Interval::default(), |a, b| a + b)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Interval {
+            lo: lower(add_err(self.lo, rhs.lo)),
+            hi: upper(add_err(self.hi, rhs.hi)),
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Interval {
+            lo: lower(add_err(self.lo, -rhs.hi)),
+            hi: upper(add_err(self.hi, -rhs.lo)),
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let p = [
+            mul_err(self.lo, rhs.lo),
+            mul_err(self.lo, rhs.hi),
+            mul_err(self.hi, rhs.lo),
+            mul_err(self.hi, rhs.hi),
+        ];
+        Interval {
+            lo: min4(p.map(lower)),
+            hi: max4(p.map(upper)),
+        }
+    }
+}
+
+// dividing by an interval that contains zero gives the whole real line
+impl Div for Interval {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains_zero() {
+            return Interval::entire();
+        }
+
+        let q = [
+            div_err(self.lo, rhs.lo),
+            div_err(self.lo, rhs.hi),
+            div_err(self.hi, rhs.lo),
+            div_err(self.hi, rhs.hi),
+        ];
+        Interval {
+            lo: min4(q.map(lower)),
+            hi: max4(q.map(upper)),
+        }
+    }
+}
+
+macro_rules! assign {
+    ($tr:ident, $f:ident, $op:tt) => {
+        impl $tr for Interval {
+            fn $f(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+assign!(AddAssign, add_assign, +);
+assign!(SubAssign, sub_assign, -);
+assign!(MulAssign, mul_assign, *);
+assign!(DivAssign, div_assign, /);
+
+// floating point inverse of the midpoint matrix, `None` if it is singular
+fn mid_inverse(mat: &Matrix<Interval>) -> Option<Matrix<f64>> {
+    let n = mat.rows;
+    let mut aug = Matrix::new(n, n * 2, vec![0.; n * n * 2]);
+    for i in 0..n {
+        for j in 0..n {
+            aug[i][j] = mat[(i, j)].mid();
+        }
+        aug[i][n + i] = 1.;
+    }
+    aug.row_echelon_mut();
+
+    let mut vec = Vec::with_capacity(n * n);
+    for i in 0..n {
+        if aug[i][i] != 1. {
+            return None;
+        }
+        vec.extend_from_slice(&aug[i][n..]);
+    }
+    Some(Matrix::new(n, n, vec))
+}
+
+// upper bound of the infinity norm
+fn norm_inf_hi(mat: &Matrix<Interval>) -> f64 {
+    mat.rows()
+        .map(|row| row.map(|x| x.abs()).sum::<Interval>().hi)
+        .fold(0., f64::max)
+}
+
+impl Matrix<Interval> {
+    pub fn mid(&self) -> Matrix<f64> {
+        self.map(|x| x.mid())
+    }
+
+    // interval Gaussian elimination with the pivot of largest mignitude,
+    // `None` when every candidate pivot contains zero or is NaN
+    pub fn gauss_solve(
+        &self,
+        b: &Vector<Interval>,
+    ) -> Option<Vector<Interval>> {
+        assert!(self.is_square(), "matrix must be squared");
+        assert_eq!(self.rows, b.size(), "vector must match the matrix size");
+
+        let n = self.rows;
+        let mut a = self.to_layout(Layout::RowMajor);
+        let mut b = b.clone();
+
+        for k in 0..n {
+            // entries with a NaN bound can not serve as a pivot
+            let p = (k..n)
+                .filter(|&i| !a[i][k].mig().is_nan())
+                .max_by(|&i, &j| a[i][k].mig().total_cmp(&a[j][k].mig()))?;
+            if !a[p][k].is_non_zero() {
+                return None;
+            }
+            if p != k {
+                for c in 0..n {
+                    a._d.swap(k * n + c, p * n + c);
+                }
+                let t = b[k];
+                b[k] = b[p];
+                b[p] = t;
+            }
+
+            for i in k + 1..n {
+                let f = a[i][k] / a[k][k];
+                a[i][k] = Interval::default();
+                for j in k + 1..n {
+                    let v = a[k][j];
+                    a[i][j] -= f * v;
+                }
+                let v = b[k];
+                b[i] -= f * v;
+            }
+        }
+
+        let mut x = Vector::from(vec![Interval::default(); n]);
+        for i in (0..n).rev() {
+            let mut s = b[i];
+            for j in i + 1..n {
+                s -= a[i][j] * x[j];
+            }
+            x[i] = s / a[i][i];
+        }
+        Some(x)
+    }
+
+    // with R ≈ mid(A)⁻¹, ‖I - RA‖ < 1 proves that every matrix of the
+    // interval matrix is non-singular
+    pub fn certify_nonsingular(&self) -> bool {
+        assert!(self.is_square(), "matrix must be squared");
+
+        match mid_inverse(self) {
+            Some(r) => self.contraction(&r) < 1.,
+            None => false,
+        }
+    }
+
+    fn contraction(&self, r: &Matrix<f64>) -> f64 {
+        norm_inf_hi(&self.residual(r))
+    }
+
+    // I - RA in interval arithmetic
+    fn residual(&self, r: &Matrix<f64>) -> Matrix<Interval> {
+        let n = self.rows;
+        let mut c = r.map(Interval::point).mul_mat(self).map(|v| -v);
+        for i in 0..n {
+            c[(i, i)] += Interval::one();
+        }
+        c
+    }
+
+    // Krawczyk iteration, the returned box contains the solution of
+    // A x = b for every A and b in the intervals. `None` when the matrix
+    // cannot be certified non-singular
+    pub fn krawczyk_solve(
+        &self,
+        b: &Vector<Interval>,
+    ) -> Option<Vector<Interval>> {
+        assert!(self.is_square(), "matrix must be squared");
+        assert_eq!(self.rows, b.size(), "vector must match the matrix size");
+
+        let r = mid_inverse(self)?;
+        let c = self.residual(&r);
+        let norm_c = norm_inf_hi(&c);
+        if norm_c >= 1. {
+            return None;
+        }
+
+        let ri = r.map(Interval::point);
+        let x0 = r.mul_vec(&b.map(|x| x.mid())).map(Interval::point);
+        let z = ri.mul_vec(&(b.clone() - self.mul_vec(&x0)));
+
+        // |x - x0| <= ‖z‖ / (1 - ‖C‖)
+        let z_norm = z.fold(0., |m, v| v.abs().hi.max(m));
+        let rad = (Interval::point(z_norm)
+            / (Interval::one() - Interval::point(norm_c)))
+        .hi;
+        let mut x = x0.map(|v| v + Interval::new(-rad, rad));
+
+        for _ in 0..8 {
+            let k =
+                x0.clone() + z.clone() + c.mul_vec(&(x.clone() - x0.clone()));
+            x = x.zip_map(&k, |a, b| a.intersect(&b).unwrap_or(a));
+        }
+        Some(x)
+    }
+}
@@ -1,14 +1,45 @@
+pub mod bigint;
 pub mod complex;
+pub mod dual;
+pub mod expr;
 pub mod f32;
 pub mod f64;
+pub mod fixed;
+pub mod gf2;
+pub mod half;
+pub mod integer;
+pub mod interval;
+mod lll;
 pub mod matrix;
+pub mod modular;
+mod normal_form;
+pub mod polynomial;
+pub mod quaternion;
+pub mod rational;
 pub mod scalar;
+pub mod simd;
+mod strassen;
 pub mod utils;
 pub mod vec2;
 pub mod vec3;
 pub mod vector;
 
-pub use complex::Complex;
-pub use matrix::{projection, Matrix, Transpose};
-pub use scalar::{lerp, Scalar};
-pub use vector::{angle_cos, cross_product, linear_combination, Dot, Vector};
+pub use bigint::BigInt;
+pub use complex::{Complex, Complex32, Complex64};
+pub use dual::{derivative, gradient, jacobian, Dual};
+pub use expr::Expr;
+pub use fixed::{Fixed, Q16_16, Q1_31};
+pub use gf2::{BitMatrix, Gf2};
+pub use half::{Bf16, F16};
+pub use interval::Interval;
+pub use matrix::{projection, Layout, Matrix, Transpose};
+pub use modular::Zp;
+pub use polynomial::Polynomial;
+pub use quaternion::Quaternion;
+pub use rational::{BigRational, Rational, Rational128, Rational64};
+pub use scalar::{
+    lerp, ComplexField, Field, RealField, Ring, Scalar, Semiring,
+};
+pub use vector::{
+    angle_cos, cross_product, linear_combination, orthonormalize, Dot, Vector,
+};
@@ -0,0 +1,166 @@
+use crate::{integer::Int, matrix::Layout, Matrix, Rational};
+
+impl<T: Int> Matrix<Rational<T>> {
+    // Lenstra-Lenstra-Lovasz reduction of the basis given as rows, exact
+    // over the rationals. Returns the reduced basis and the unimodular U
+    // with U B = reduced, `None` when an intermediate value overflows
+    pub fn checked_lll(
+        &self,
+        delta: Rational<T>,
+    ) -> Option<(Matrix<Rational<T>>, Matrix<T>)> {
+        let quarter = Rational::new(T::one(), T::from_f64(4.)?);
+        assert!(
+            delta > quarter && delta <= Rational::from(T::one()),
+            "delta must lie in (1/4, 1]"
+        );
+
+        let (n, m) = (self.rows, self.cols);
+        let mat = self.to_layout(Layout::RowMajor);
+        let mut b: Vec<Vec<Rational<T>>> =
+            (0..n).map(|i| mat[i].to_vec()).collect();
+        let mut u: Vec<Vec<T>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if i == j { T::one() } else { T::zero() })
+                    .collect()
+            })
+            .collect();
+
+        // Gram-Schmidt coefficients mu and squared lengths of the
+        // orthogonalized vectors, only those are kept up to date
+        let mut mu = vec![vec![Rational::default(); n]; n];
+        let mut len: Vec<Rational<T>> = Vec::with_capacity(n);
+        let mut star: Vec<Vec<Rational<T>>> = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut s = b[i].clone();
+            for j in 0..i {
+                mu[i][j] = dot(&b[i], &star[j])?.checked_div(len[j].clone())?;
+                for k in 0..m {
+                    s[k] = s[k].clone().checked_sub(
+                        mu[i][j].clone().checked_mul(star[j][k].clone())?,
+                    )?;
+                }
+            }
+            let l = dot(&s, &s)?;
+            assert!(
+                l != Rational::default(),
+                "basis vectors must be linearly independent"
+            );
+            len.push(l);
+            star.push(s);
+        }
+
+        let mut k = 1;
+        while k < n {
+            size_reduce(&mut b, &mut u, &mut mu, k, k - 1)?;
+
+            // Lovasz condition |b*_k|^2 >= (delta - mu^2) |b*_(k-1)|^2
+            let mu2 = mu[k][k - 1].clone().checked_mul(mu[k][k - 1].clone())?;
+            let bound = delta
+                .clone()
+                .checked_sub(mu2)?
+                .checked_mul(len[k - 1].clone())?;
+            if len[k] >= bound {
+                for j in (0..k - 1).rev() {
+                    size_reduce(&mut b, &mut u, &mut mu, k, j)?;
+                }
+                k += 1;
+                continue;
+            }
+
+            b.swap(k, k - 1);
+            u.swap(k, k - 1);
+            let (lo, hi) = mu.split_at_mut(k);
+            lo[k - 1][..k - 1].swap_with_slice(&mut hi[0][..k - 1]);
+
+            let c = mu[k][k - 1].clone();
+            let l = len[k].clone().checked_add(
+                c.clone()
+                    .checked_mul(c.clone())?
+                    .checked_mul(len[k - 1].clone())?,
+            )?;
+            mu[k][k - 1] = c
+                .clone()
+                .checked_mul(len[k - 1].clone())?
+                .checked_div(l.clone())?;
+            len[k] = len[k - 1]
+                .clone()
+                .checked_mul(len[k].clone())?
+                .checked_div(l.clone())?;
+            len[k - 1] = l;
+            for i in k + 1..n {
+                let t = mu[i][k].clone();
+                mu[i][k] = mu[i][k - 1]
+                    .clone()
+                    .checked_sub(c.clone().checked_mul(t.clone())?)?;
+                mu[i][k - 1] = t.checked_add(
+                    mu[k][k - 1].clone().checked_mul(mu[i][k].clone())?,
+                )?;
+            }
+            k = (k - 1).max(1);
+        }
+
+        Some((Matrix::new(n, m, b.concat()), Matrix::new(n, n, u.concat())))
+    }
+}
+
+impl<T: Int> Matrix<T> {
+    // LLL reduction of an integer basis given as rows
+    pub fn checked_lll(
+        &self,
+        delta: Rational<T>,
+    ) -> Option<(Matrix<T>, Matrix<T>)> {
+        let (b, u) = self.map(Rational::from).checked_lll(delta)?;
+        Some((b.map(|v| v.numer().clone()), u))
+    }
+}
+
+fn dot<T: Int>(a: &[Rational<T>], b: &[Rational<T>]) -> Option<Rational<T>> {
+    a.iter()
+        .zip(b)
+        .try_fold(Rational::default(), |acc, (x, y)| {
+            acc.checked_add(x.clone().checked_mul(y.clone())?)
+        })
+}
+
+// nearest integer, halves round up
+fn round<T: Int>(v: &Rational<T>) -> Option<T> {
+    let (num, den) = (v.numer(), v.denom());
+    let (q, r) = (num.div_euclid(den), num.rem_euclid(den));
+    // 2 r >= den without forming 2 r
+    if r.checked_add(&r.checked_sub(den)?)? >= T::zero() {
+        q.checked_add(&T::one())
+    } else {
+        Some(q)
+    }
+}
+
+// b_k -= q b_j with q the nearest integer to mu_kj, leaving |mu_kj| <= 1/2
+fn size_reduce<T: Int>(
+    b: &mut [Vec<Rational<T>>],
+    u: &mut [Vec<T>],
+    mu: &mut [Vec<Rational<T>>],
+    k: usize,
+    j: usize,
+) -> Option<()> {
+    let q = round(&mu[k][j])?;
+    if q == T::zero() {
+        return Some(());
+    }
+
+    let qr = Rational::from(q.clone());
+    for i in 0..b[k].len() {
+        b[k][i] = b[k][i]
+            .clone()
+            .checked_sub(qr.clone().checked_mul(b[j][i].clone())?)?;
+    }
+    for i in 0..u[k].len() {
+        u[k][i] = u[k][i].checked_sub(&q.checked_mul(&u[j][i])?)?;
+    }
+    let (lo, hi) = mu.split_at_mut(k);
+    for (m, l) in hi[0][..j].iter_mut().zip(&lo[j][..j]) {
+        *m = m.clone().checked_sub(qr.clone().checked_mul(l.clone())?)?;
+    }
+    mu[k][j] = mu[k][j].clone().checked_sub(qr)?;
+    Some(())
+}
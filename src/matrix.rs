@@ -1,24 +1,38 @@
 use std::{
+    borrow::Cow,
     f32::consts::PI,
     fmt::{Debug, Display},
-    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
+    ops::{
+        Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Sub, SubAssign,
+    },
 };
 
 use crate::{
-    scalar::{MulAdd, Scalar},
-    vector::{Dot, Vector},
+    polynomial::Polynomial,
+    scalar::{Cast, ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring},
+    vector::{orthonormalize, Vector},
     V,
 };
 
 #[derive(Clone)]
 pub struct Matrix<K> {
-    pub _d: Vec<K>,
+    pub(crate) _d: Vec<K>,
     pub rows: usize,
     pub cols: usize,
+    // changing it without reordering `_d` would transpose the data
+    pub(crate) layout: Layout,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    #[default]
+    RowMajor,
+    ColMajor,
 }
 
 pub trait Transpose<K> {
     fn transpose(&self) -> Matrix<K>;
+    fn conjugate_transpose(&self) -> Matrix<K>;
 }
 
 #[macro_export]
@@ -28,11 +42,12 @@ macro_rules! M {
     };
 }
 
-impl<K: Scalar> Debug for Matrix<K> {
+impl<K: Debug + Clone> Debug for Matrix<K> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mat = self.in_layout(Layout::RowMajor);
         f.write_str("[")?;
         for i in 0..self.rows {
-            write!(f, "{:?}", &self[i])?;
+            write!(f, "{:?}", &mat[i])?;
             if i != self.rows - 1 {
                 writeln!(f, ",")?;
             }
@@ -42,11 +57,12 @@ impl<K: Scalar> Debug for Matrix<K> {
     }
 }
 
-impl<K: Scalar> Display for Matrix<K> {
+impl<K: Debug + Clone> Display for Matrix<K> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mat = self.in_layout(Layout::RowMajor);
         f.write_str("[")?;
         for i in 0..self.rows {
-            write!(f, "{:?}", &self[i])?;
+            write!(f, "{:?}", &mat[i])?;
             if i != self.rows - 1 {
                 writeln!(f, ",")?;
             }
@@ -59,24 +75,29 @@ impl<K: Scalar> Display for Matrix<K> {
 impl<K: Clone, const N: usize, const D: usize> From<[[K; D]; N]> for Matrix<K> {
     fn from(value: [[K; D]; N]) -> Self {
         let mut vec = Vec::with_capacity(N * D);
-        (0..N).for_each(|i| {
-            for j in 0..D {
-                vec.push(value[i][j].clone());
+        for row in &value {
+            for v in row {
+                vec.push(v.clone());
             }
-        });
+        }
 
         Matrix {
             rows: N,
             cols: D,
             _d: vec,
+            layout: Layout::RowMajor,
         }
     }
 }
 
+// a row is only contiguous in row-major storage, so `m[i]` panics on
+// column-major matrices. `row` works for both layouts
 impl<K> Index<usize> for Matrix<K> {
     type Output = [K];
 
     fn index(&self, index: usize) -> &[K] {
+        assert_eq!(self.layout, Layout::RowMajor, "matrix must be row-major");
+
         let start = index * self.cols;
         &self._d[start..start + self.cols]
     }
@@ -84,45 +105,52 @@ impl<K> Index<usize> for Matrix<K> {
 
 impl<K> IndexMut<usize> for Matrix<K> {
     fn index_mut(&mut self, index: usize) -> &mut [K] {
+        assert_eq!(self.layout, Layout::RowMajor, "matrix must be row-major");
+
         let start = index * self.cols;
         &mut self._d[start..start + self.cols]
     }
 }
 
-impl<K: Scalar> Add for Matrix<K> {
+impl<K> Index<(usize, usize)> for Matrix<K> {
+    type Output = K;
+
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        &self._d[self.offset(i, j)]
+    }
+}
+
+impl<K> IndexMut<(usize, usize)> for Matrix<K> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut K {
+        let offset = self.offset(i, j);
+        &mut self._d[offset]
+    }
+}
+
+impl<K: Semiring> Add for Matrix<K> {
     type Output = Self;
 
-    fn add(self, other: Self) -> Self::Output {
+    fn add(mut self, other: Self) -> Self::Output {
         assert_eq!(
             self.shape(),
             other.shape(),
             "matrices must be the same size"
         );
 
-        let mut vec = Vec::with_capacity(self.rows * self.cols);
-        for i in 0..self._d.len() {
-            vec.push(self._d[i] + other._d[i]);
-        }
-
-        Matrix {
-            _d: vec,
-            cols: self.cols,
-            rows: self.rows,
-        }
+        K::slice_add(&mut self._d, &other.in_layout(self.layout)._d);
+        self
     }
 }
 
-impl<K: Scalar> AddAssign<&Matrix<K>> for Matrix<K> {
+impl<K: Semiring> AddAssign<&Matrix<K>> for Matrix<K> {
     fn add_assign(&mut self, rhs: &Matrix<K>) {
         assert_eq!(self.shape(), rhs.shape(), "matrices must be the same size");
 
-        for i in 0..self._d.len() {
-            self._d[i] += rhs._d[i]
-        }
+        K::slice_add(&mut self._d, &rhs.in_layout(self.layout)._d);
     }
 }
 
-impl<K: Scalar> Sub for Matrix<K> {
+impl<K: Ring> Sub for Matrix<K> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self::Output {
@@ -132,78 +160,81 @@ impl<K: Scalar> Sub for Matrix<K> {
             "matrices must be the same size"
         );
 
+        let other = other.in_layout(self.layout);
         let mut vec = Vec::with_capacity(self.rows * self.cols);
         for i in 0..self._d.len() {
-            vec.push(self._d[i] - other._d[i]);
+            vec.push(self._d[i].clone() - other._d[i].clone());
         }
 
         Matrix {
             _d: vec,
             cols: self.cols,
             rows: self.rows,
+            layout: self.layout,
         }
     }
 }
 
-impl<K: Scalar> SubAssign<&Matrix<K>> for Matrix<K> {
+impl<K: Ring> SubAssign<&Matrix<K>> for Matrix<K> {
     fn sub_assign(&mut self, rhs: &Matrix<K>) {
         assert_eq!(self.shape(), rhs.shape(), "matrices must be the same size");
 
+        let rhs = rhs.in_layout(self.layout);
         for i in 0..self._d.len() {
-            self._d[i] -= rhs._d[i];
+            self._d[i] -= rhs._d[i].clone();
         }
     }
 }
 
-impl<K: Scalar + Mul<U, Output = K>, U: Scalar> Mul<U> for Matrix<K> {
+impl<K: Semiring + Mul<U, Output = K>, U: Semiring> Mul<U> for Matrix<K> {
     type Output = Matrix<K>;
 
     fn mul(self, a: U) -> Self::Output {
         let mut vec = Vec::with_capacity(self._d.len());
         for i in 0..self._d.len() {
-            vec.push(self._d[i] * a);
+            vec.push(self._d[i].clone() * a.clone());
         }
 
         Matrix {
             _d: vec,
             cols: self.cols,
             rows: self.rows,
+            layout: self.layout,
         }
     }
 }
 
-impl<K: Scalar + MulAssign<U>, U: Scalar> MulAssign<&U> for Matrix<K> {
+impl<K: Semiring + MulAssign<U>, U: Semiring> MulAssign<&U> for Matrix<K> {
     fn mul_assign(&mut self, rhs: &U) {
         for i in 0..self._d.len() {
-            self._d[i] *= *rhs;
+            self._d[i] *= rhs.clone();
         }
     }
 }
 
-impl<K: Scalar + Mul<U, Output = K> + MulAdd<U, K>, U: Scalar>
+impl<K: Semiring + Mul<U, Output = K> + MulAdd<U, K>, U: Semiring>
     MulAdd<U, Matrix<K>> for Matrix<K>
 {
     fn mul_add(self, a: &U, b: &Matrix<K>) -> Self {
         assert!(self.shape() == b.shape(), "matrices must be the same size");
 
+        let b = b.in_layout(self.layout);
         let mut vec = Vec::with_capacity(self.rows * self.cols);
 
         for i in 0..self._d.len() {
-            vec.push(self._d[i].mul_add(a, &b._d[i]));
+            vec.push(self._d[i].clone().mul_add(a, &b._d[i]));
         }
 
         Matrix {
             _d: vec,
             rows: self.rows,
             cols: self.cols,
+            layout: self.layout,
         }
     }
 }
 
-impl<'a, K: Scalar> Mul<&Vector<K>> for &Matrix<K>
-where
-    [K]: Dot<K>,
-{
+impl<K: Semiring> Mul<&Vector<K>> for &Matrix<K> {
     type Output = Vector<K>;
 
     fn mul(self, rhs: &Vector<K>) -> Self::Output {
@@ -213,15 +244,16 @@ where
             "bad input for matrix and vector column multiplication"
         );
 
+        let mat = self.in_layout(Layout::RowMajor);
         let mut vec = Vec::with_capacity(rhs.size());
         for i in 0..rhs.size() {
-            vec.push(self[i].dot(rhs));
+            vec.push(K::slice_mul_sum(&mat[i], &rhs._d));
         }
         V!(vec)
     }
 }
 
-impl<K: Scalar> Mul<&Matrix<K>> for &Matrix<K> {
+impl<K: Semiring> Mul<&Matrix<K>> for &Matrix<K> {
     type Output = Matrix<K>;
 
     fn mul(self, rhs: &Matrix<K>) -> Self::Output {
@@ -230,17 +262,18 @@ impl<K: Scalar> Mul<&Matrix<K>> for &Matrix<K> {
             "bad input for matrix and matrix multiplication"
         );
 
-        let cols = rhs.cols;
-        let rows = self.rows;
+        // rows of lhs against contiguous columns of rhs
+        let (lhs, rhs) = (
+            self.in_layout(Layout::RowMajor),
+            rhs.in_layout(Layout::ColMajor),
+        );
+        let (rows, cols, n) = (lhs.rows, rhs.cols, rhs.rows);
         let mut vec = Vec::with_capacity(rows * cols);
 
         for i in 0..rows {
             for j in 0..cols {
-                let mut val = self[i][0] * rhs[0][j];
-                for r in 1..self.cols {
-                    val += self[i][r] * rhs[r][j];
-                }
-                vec.push(val);
+                let col = &rhs._d[j * n..(j + 1) * n];
+                vec.push(K::slice_mul_sum(&lhs[i], col));
             }
         }
 
@@ -248,11 +281,73 @@ impl<K: Scalar> Mul<&Matrix<K>> for &Matrix<K> {
             _d: vec,
             rows,
             cols,
+            layout: Layout::RowMajor,
         }
     }
 }
 
-impl<K: Scalar> Matrix<K> {
+impl<K: Semiring> Transpose<K> for Matrix<K> {
+    fn transpose(&self) -> Matrix<K> {
+        let mut vec = Vec::with_capacity(self.rows * self.cols);
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                vec.push(self[(j, i)].clone());
+            }
+        }
+
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            _d: vec,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    fn conjugate_transpose(&self) -> Matrix<K> {
+        let mut ret = self.transpose();
+        for v in ret._d.iter_mut() {
+            *v = v.clone().conj();
+        }
+        ret
+    }
+}
+
+impl<K: Lerp + Clone> Lerp for Matrix<K> {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => {
+                let v = v.into_layout(u.layout);
+                let mut vec = Vec::with_capacity(u._d.len());
+
+                for i in 0..u._d.len() {
+                    vec.push(K::lerp_elem(u._d[i].clone(), v._d[i].clone(), p))
+                }
+
+                Matrix {
+                    _d: vec,
+                    cols: u.cols,
+                    rows: u.rows,
+                    layout: u.layout,
+                }
+            }
+        }
+    }
+}
+
+impl<K> Matrix<K> {
+    pub fn new(rows: usize, cols: usize, data: Vec<K>) -> Self {
+        assert_eq!(rows * cols, data.len(), "data must match the matrix size");
+
+        Matrix {
+            _d: data,
+            rows,
+            cols,
+            layout: Layout::RowMajor,
+        }
+    }
+
     pub fn shape(&self) -> (usize, usize) {
         (self.rows, self.cols)
     }
@@ -261,22 +356,184 @@ impl<K: Scalar> Matrix<K> {
         self.rows == self.cols
     }
 
-    pub fn add(&mut self, v: &Matrix<K>) {
-        *self += v;
+    pub fn layout(&self) -> Layout {
+        self.layout
     }
 
-    pub fn sub(&mut self, v: &Matrix<K>) {
-        *self -= v;
+    fn offset(&self, i: usize, j: usize) -> usize {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+
+        match self.layout {
+            Layout::RowMajor => i * self.cols + j,
+            Layout::ColMajor => j * self.rows + i,
+        }
     }
 
-    pub fn scl(&mut self, a: K) {
-        *self *= &a;
+    pub fn into_transpose(self) -> Matrix<K> {
+        Matrix {
+            _d: self._d,
+            rows: self.cols,
+            cols: self.rows,
+            layout: match self.layout {
+                Layout::RowMajor => Layout::ColMajor,
+                Layout::ColMajor => Layout::RowMajor,
+            },
+        }
+    }
+
+    // elements are visited in storage order, which is row by row for
+    // row-major matrices and column by column for column-major ones
+    pub fn iter(&self) -> std::slice::Iter<'_, K> {
+        self._d.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, K> {
+        self._d.iter_mut()
+    }
+
+    pub fn enumerate_indexed(
+        &self,
+    ) -> impl Iterator<Item = ((usize, usize), &K)> + '_ {
+        self._d.iter().enumerate().map(|(n, v)| {
+            let index = match self.layout {
+                Layout::RowMajor => (n / self.cols, n % self.cols),
+                Layout::ColMajor => (n % self.rows, n / self.rows),
+            };
+            (index, v)
+        })
     }
 
-    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K>
+    pub fn row(&self, i: usize) -> impl Iterator<Item = &K> + '_ {
+        assert!(i < self.rows, "index out of bounds");
+        (0..self.cols).map(move |j| &self[(i, j)])
+    }
+
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &K> + '_ {
+        assert!(j < self.cols, "index out of bounds");
+        (0..self.rows).map(move |i| &self[(i, j)])
+    }
+
+    pub fn rows(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &K> + '_> + '_ {
+        (0..self.rows).map(move |i| self.row(i))
+    }
+
+    pub fn cols(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &K> + '_> + '_ {
+        (0..self.cols).map(move |j| self.col(j))
+    }
+}
+
+impl<K: Clone> Matrix<K> {
+    pub fn map<U, F: FnMut(K) -> U>(&self, f: F) -> Matrix<U> {
+        Matrix {
+            _d: self._d.iter().cloned().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+            layout: self.layout,
+        }
+    }
+
+    pub fn zip_map<V: Clone, U, F: FnMut(K, V) -> U>(
+        &self,
+        other: &Matrix<V>,
+        mut f: F,
+    ) -> Matrix<U> {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "matrices must be the same size"
+        );
+
+        let other = other.in_layout(self.layout);
+        Matrix {
+            _d: self
+                ._d
+                .iter()
+                .zip(&other._d)
+                .map(|(a, b)| f(a.clone(), b.clone()))
+                .collect(),
+            rows: self.rows,
+            cols: self.cols,
+            layout: self.layout,
+        }
+    }
+
+    pub fn fold<B, F: FnMut(B, K) -> B>(&self, init: B, f: F) -> B {
+        self._d.iter().cloned().fold(init, f)
+    }
+
+    pub fn cast<U>(&self) -> Matrix<U>
     where
-        [K]: Dot<K>,
+        K: Cast<U>,
     {
+        self.map(K::cast)
+    }
+}
+
+impl<K: Clone> Matrix<K> {
+    pub fn to_layout(&self, layout: Layout) -> Matrix<K> {
+        if self.layout == layout {
+            return self.clone();
+        }
+
+        let (outer, inner) = match layout {
+            Layout::RowMajor => (self.rows, self.cols),
+            Layout::ColMajor => (self.cols, self.rows),
+        };
+
+        let mut vec = Vec::with_capacity(self._d.len());
+        for o in 0..outer {
+            for i in 0..inner {
+                vec.push(self._d[i * outer + o].clone());
+            }
+        }
+
+        Matrix {
+            _d: vec,
+            rows: self.rows,
+            cols: self.cols,
+            layout,
+        }
+    }
+
+    pub fn into_layout(self, layout: Layout) -> Matrix<K> {
+        if self.layout == layout {
+            self
+        } else {
+            self.to_layout(layout)
+        }
+    }
+
+    pub(crate) fn in_layout(&self, layout: Layout) -> Cow<'_, Matrix<K>> {
+        if self.layout == layout {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.to_layout(layout))
+        }
+    }
+}
+
+impl<K: Semiring> Matrix<K> {
+    pub fn identity(n: usize) -> Self {
+        let mut ret = Matrix::new(n, n, vec![K::default(); n * n]);
+        for i in 0..n {
+            ret[(i, i)] = K::one();
+        }
+        ret
+    }
+
+    pub fn add(&mut self, v: &Matrix<K>) {
+        *self += v;
+    }
+
+    pub fn scl(&mut self, a: K) {
+        K::slice_scale(&mut self._d, a);
+    }
+
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
         self * vec
     }
 
@@ -289,10 +546,182 @@ impl<K: Scalar> Matrix<K> {
 
         match self.rows {
             0 => None,
-            _ => Some((0..self.rows).map(|i| self[i][i]).sum()),
+            _ => Some(
+                (0..self.rows)
+                    .map(|i| self[(i, i)].clone())
+                    .fold(K::default(), |a, b| a + b),
+            ),
         }
     }
+}
+
+impl<K: Ring> Matrix<K> {
+    pub fn sub(&mut self, v: &Matrix<K>) {
+        *self -= v;
+    }
+
+    // det(xI - A), the constant term comes first
+    pub fn char_poly(&self) -> Polynomial<K> {
+        let mut c = self.berkowitz();
+        c.reverse();
+        Polynomial::new(c)
+    }
 
+    // transpose of the cofactor matrix, A adj(A) = det(A) I also holds for
+    // singular matrices. By Cayley-Hamilton adj(A) = (-1)^(n+1) (A^(n-1) +
+    // c_(n-1) A^(n-2) + ... + c_1 I)
+    pub fn adjugate(&self) -> Matrix<K> {
+        let c = self.berkowitz();
+        let n = self.rows;
+        let a = self.to_layout(Layout::RowMajor);
+
+        let mut m = Matrix::identity(n);
+        for v in c.iter().take(n).skip(1) {
+            m = a.mul_mat(&m);
+            for j in 0..n {
+                m[(j, j)] += v.clone();
+            }
+        }
+        if n.is_multiple_of(2) {
+            m.map(|v| -v)
+        } else {
+            m
+        }
+    }
+
+    // Berkowitz: the characteristic polynomial of each leading principal
+    // submatrix follows from the previous one through a Toeplitz product,
+    // no division is needed so it works over any commutative ring. The
+    // leading coefficient comes first
+    fn berkowitz(&self) -> Vec<K> {
+        assert!(self.is_square(), "matrix must be squared");
+
+        let a = self.to_layout(Layout::RowMajor);
+        let mut p = vec![K::one()];
+        for r in 0..self.rows {
+            // first column of the Toeplitz matrix: 1, -a_rr, -R S,
+            // -R A_r S, ... with A_r the leading r x r block, S the column
+            // above a_rr and R the row left of it
+            let mut t = vec![K::one(), -a[(r, r)].clone()];
+            let mut s: Vec<K> = (0..r).map(|i| a[(i, r)].clone()).collect();
+            for _ in 0..r {
+                let rs = (0..r).fold(K::default(), |acc, j| {
+                    acc + a[(r, j)].clone() * s[j].clone()
+                });
+                t.push(-rs);
+                s = (0..r)
+                    .map(|i| {
+                        (0..r).fold(K::default(), |acc, j| {
+                            acc + a[(i, j)].clone() * s[j].clone()
+                        })
+                    })
+                    .collect();
+            }
+
+            p = (0..r + 2)
+                .map(|i| {
+                    (0..=i.min(r)).fold(K::default(), |acc, j| {
+                        acc + t[i - j].clone() * p[j].clone()
+                    })
+                })
+                .collect();
+        }
+        p
+    }
+}
+
+// exact division is only needed by the fraction-free path
+impl<K: Ring + Div<Output = K>> Matrix<K> {
+    pub fn determinant(&self) -> K {
+        assert!(self.is_square(), "matrix must be squared");
+
+        if K::EXACT {
+            return self.bareiss_determinant();
+        }
+
+        fn det<K: Ring>(
+            mat: &[K],
+            row_size: usize,
+            cur: usize,
+            skip_cols: &[bool],
+        ) -> K {
+            let mut cols = Vec::with_capacity(skip_cols.len());
+
+            for (col, skip) in skip_cols.iter().enumerate() {
+                if !*skip {
+                    cols.push(col);
+                }
+            }
+
+            match cur {
+                0 => K::default(),
+                1 => mat[0].clone(),
+                2 => {
+                    mat[cols[0]].clone() * mat[row_size + cols[1]].clone()
+                        - mat[cols[1]].clone() * mat[row_size + cols[0]].clone()
+                }
+                _ => {
+                    let mut result = K::default();
+                    for (i, col) in cols.iter().enumerate() {
+                        let mut m_col = skip_cols.to_vec();
+                        m_col[*col] = true;
+                        let ret = mat[*col].clone()
+                            * det(&mat[row_size..], row_size, cur - 1, &m_col);
+                        result += if i % 2 == 1 { -ret } else { ret };
+                        m_col[*col] = false;
+                    }
+                    result
+                }
+            }
+        }
+
+        let mat = self.in_layout(Layout::RowMajor);
+        det(
+            mat._d.as_slice(),
+            self.rows,
+            self.rows,
+            &vec![false; self.cols],
+        )
+    }
+
+    // fraction-free Gaussian elimination, every division is exact so it
+    // also works over integer rings
+    fn bareiss_determinant(&self) -> K {
+        let mut mat = self.to_layout(Layout::RowMajor);
+        let n = self.rows;
+        let mut prev = K::one();
+        let mut negate = false;
+
+        for k in 0..n {
+            let Some(p) = (k..n).find(|&i| mat[i][k].is_non_zero()) else {
+                return K::default();
+            };
+            if p != k {
+                for c in 0..n {
+                    mat._d.swap(k * n + c, p * n + c);
+                }
+                negate = !negate;
+            }
+
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let v = mat[i][j].clone() * mat[k][k].clone()
+                        - mat[i][k].clone() * mat[k][j].clone();
+                    mat[i][j] = v / prev.clone();
+                }
+            }
+            prev = mat[k][k].clone();
+        }
+
+        if negate {
+            -prev
+        } else {
+            prev
+        }
+    }
+}
+
+impl<K: Field> Matrix<K> {
     pub fn row_echelon(&self) -> Matrix<K> {
         let mut ret = self.clone();
         ret.row_echelon_mut();
@@ -300,6 +729,10 @@ impl<K: Scalar> Matrix<K> {
     }
 
     pub fn row_echelon_mut(&mut self) -> &mut Self {
+        if self.layout != Layout::RowMajor {
+            *self = self.to_layout(Layout::RowMajor);
+        }
+
         let mut col: usize = 0;
 
         for r in 0..self.rows {
@@ -325,28 +758,26 @@ impl<K: Scalar> Matrix<K> {
             col = cur.1;
             if r != cur.0 {
                 for c in col..self.cols {
-                    let tmp = self[r][c];
-                    self[r][c] = self[cur.0][c];
-                    self[cur.0][c] = tmp;
+                    self._d.swap(r * self.cols + c, cur.0 * self.cols + c);
                 }
             }
 
-            let v = self[r][col];
+            let v = self[r][col].clone();
 
             self[r][col] = K::one();
             for i in col + 1..self.cols {
-                self[r][i] /= v;
+                self[r][i] /= v.clone();
             }
 
             for i in 0..self.rows {
                 if i == r {
                     continue;
                 }
-                let cur = self[i][col];
+                let cur = self[i][col].clone();
                 self[i][col] = K::default();
                 for j in col + 1..self.cols {
-                    let x = self[r][j];
-                    self[i][j] -= x * cur;
+                    let x = self[r][j].clone();
+                    self[i][j] -= x * cur.clone();
                 }
             }
 
@@ -356,53 +787,6 @@ impl<K: Scalar> Matrix<K> {
         self
     }
 
-    pub fn determinant(&self) -> K {
-        assert!(self.is_square(), "matrix must be squared");
-
-        fn det<K: Scalar>(
-            mat: &[K],
-            row_size: usize,
-            cur: usize,
-            skip_cols: &[bool],
-        ) -> K {
-            let mut cols = Vec::with_capacity(skip_cols.len());
-
-            for (col, skip) in skip_cols.iter().enumerate() {
-                if !*skip {
-                    cols.push(col);
-                }
-            }
-
-            match cur {
-                0 => K::default(),
-                1 => mat[0],
-                2 => {
-                    mat[cols[0]] * mat[row_size + cols[1]]
-                        - mat[cols[1]] * mat[row_size + cols[0]]
-                }
-                _ => {
-                    let mut result = K::default();
-                    for (i, col) in cols.iter().enumerate() {
-                        let mut m_col = skip_cols.to_vec();
-                        m_col[*col] = true;
-                        let ret = mat[*col]
-                            * det(&mat[row_size..], row_size, cur - 1, &m_col);
-                        result += if i % 2 == 1 { -ret } else { ret };
-                        m_col[*col] = false;
-                    }
-                    result
-                }
-            }
-        }
-
-        det(
-            self._d.as_slice(),
-            self.rows,
-            self.rows,
-            &vec![false; self.cols],
-        )
-    }
-
     pub fn inverse(&self) -> Result<Matrix<K>, &'static str> {
         assert!(self.is_square(), "matrix must be squared");
 
@@ -412,11 +796,13 @@ impl<K: Scalar> Matrix<K> {
             _d: vec![K::default(); self.rows * self.cols * 2],
             cols: self.cols * 2,
             rows: self.rows,
+            layout: Layout::RowMajor,
         };
 
         for row in 0..self.rows {
             for col in 0..self.cols {
-                aug_m._d[row * (self.cols * 2) + col] = self[row][col];
+                aug_m._d[row * (self.cols * 2) + col] =
+                    self[(row, col)].clone();
             }
             aug_m._d[row * (self.cols * 2) + self.cols + row] = K::one();
         }
@@ -426,15 +812,14 @@ impl<K: Scalar> Matrix<K> {
         let mut vec = Vec::with_capacity(self.rows * self.cols);
 
         for row in aug_m._d.chunks(self.cols * 2) {
-            for &v in row.iter().skip(self.cols) {
-                vec.push(v);
-            }
+            vec.extend_from_slice(&row[self.cols..]);
         }
 
         Ok(Matrix {
             _d: vec,
             cols: self.cols,
             rows: self.rows,
+            layout: Layout::RowMajor,
         })
     }
 
@@ -469,7 +854,7 @@ impl<K: Scalar> Matrix<K> {
         for row in mat._d.chunks(self.cols) {
             for (j, v) in row.iter().enumerate().skip(cur) {
                 if *v != K::default() {
-                    vec.copy_from_slice(row);
+                    vec.extend_from_slice(row);
                     cur = j + 1;
                     rank += 1;
                     break;
@@ -481,10 +866,12 @@ impl<K: Scalar> Matrix<K> {
             cols: self.cols,
             rows: rank,
             _d: vec,
+            layout: Layout::RowMajor,
         }
     }
 
     pub fn col_space(&self) -> Matrix<K> {
+        let this = self.in_layout(Layout::RowMajor);
         let mat = self.row_echelon();
         let mut vec = vec![];
 
@@ -493,8 +880,8 @@ impl<K: Scalar> Matrix<K> {
         for row in mat._d.chunks(self.cols) {
             for (j, v) in row.iter().enumerate().skip(cur) {
                 if *v != K::default() {
-                    for r in self._d.chunks(self.cols) {
-                        vec.push(r[cur])
+                    for r in this._d.chunks(self.cols) {
+                        vec.push(r[cur].clone())
                     }
                     cur = j + 1;
                     rank += 1;
@@ -507,11 +894,100 @@ impl<K: Scalar> Matrix<K> {
             cols: self.rows,
             rows: rank,
             _d: vec,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    // basis of { x | A x = 0 }, one vector per row
+    pub fn null_space(&self) -> Matrix<K> {
+        let mat = self.row_echelon();
+        let mut pivots = vec![];
+
+        let mut cur = 0;
+        for row in mat._d.chunks(self.cols) {
+            for (j, v) in row.iter().enumerate().skip(cur) {
+                if v.is_non_zero() {
+                    pivots.push(j);
+                    cur = j + 1;
+                    break;
+                }
+            }
+        }
+
+        let mut vec = vec![];
+        let mut rows = 0;
+        for f in (0..self.cols).filter(|j| !pivots.contains(j)) {
+            let start = vec.len();
+            vec.resize(start + self.cols, K::default());
+            vec[start + f] = K::one();
+            for (r, &p) in pivots.iter().enumerate() {
+                vec[start + p] = -mat[r][f].clone();
+            }
+            rows += 1;
+        }
+
+        Matrix {
+            cols: self.cols,
+            rows,
+            _d: vec,
+            layout: Layout::RowMajor,
+        }
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    // monic polynomial of least degree with p(A) = 0, the first power of A
+    // that depends linearly on the lower ones gives it, the dependence test
+    // needs exact arithmetic so inexact types are refused at compile time
+    pub fn min_poly(&self) -> Polynomial<K> {
+        const { assert!(K::EXACT, "minimal polynomial needs an exact type") };
+        assert!(self.is_square(), "matrix must be squared");
+
+        let n = self.rows;
+        let a = self.to_layout(Layout::RowMajor);
+        let mut powers = vec![Matrix::identity(n)];
+        for d in 1..=n {
+            powers.push(powers[d - 1].mul_mat(&a));
+
+            // one column per power, the null vector found ends in one
+            let mut vec = Vec::with_capacity(n * n * (d + 1));
+            for r in 0..n * n {
+                vec.extend(powers.iter().map(|p| p._d[r].clone()));
+            }
+            let ns = Matrix::new(n * n, d + 1, vec).null_space();
+            if ns.rows > 0 {
+                return Polynomial::new(ns[0].to_vec());
+            }
+        }
+        Polynomial::one()
+    }
+}
+
+impl<K: ComplexField> Matrix<K>
+where
+    K::AbsOutput: Cast<K>,
+{
+    // orthonormal rows spanning the same space, see `orthonormalize`
+    pub fn orthonormalize_rows(
+        &self,
+        reorthogonalize: bool,
+        tol: K::AbsOutput,
+    ) -> Result<Matrix<K>, &'static str> {
+        let mat = self.to_layout(Layout::RowMajor);
+        let rows: Vec<Vector<K>> =
+            (0..self.rows).map(|i| V!(mat[i].to_vec())).collect();
+        let refs: Vec<&Vector<K>> = rows.iter().collect();
+        let basis = orthonormalize(&refs, reorthogonalize, tol)?;
+
+        let mut vec = Vec::with_capacity(self.rows * self.cols);
+        for v in basis {
+            vec.extend(v._d);
         }
+        Ok(Matrix::new(self.rows, self.cols, vec))
     }
 }
 
-pub fn projection(fov: f32, ratio: f32, n: f32, f: f32) -> Matrix<f32> {
+pub fn projection<K: Float>(fov: K, ratio: K, n: K, f: K) -> Matrix<K> {
     // let Ps the projection of P on the image plane in 3D space.
     // using the similar triangle rule:
     //  (Ps)x = near * Px / -Pz
@@ -549,15 +1025,16 @@ pub fn projection(fov: f32, ratio: f32, n: f32, f: f32) -> Matrix<f32> {
     // tan(fov / 2) = opposite / adjacent = BC / AB = top / near
     // top = tan(fov / 2) * near
     // right = top * aspect_ratio
-    let tan = ((fov / 2.) * (PI / 180.)).tan();
+    let (o, two) = (K::default(), K::from_f32(2.));
+    let tan = ((fov / two) * K::from_f32(PI / 180.)).tan();
     let t = n * tan;
     let r = t * ratio;
     let (l, b) = (-r, -t);
 
     M!([
-        [2. * n / (r - l), 0., (r + l) / (r - l), 0.], // (Ps)x
-        [0., 2. * n / (t - b), (t + b) / (t - b), 0.], // (Ps)y
-        [0., 0., f / (n - f), (n * f) / (n - f)],      // (Ps)z
-        [0., 0., -1., 0.],                             // (Ps)w
+        [two * n / (r - l), o, (r + l) / (r - l), o], // (Ps)x
+        [o, two * n / (t - b), (t + b) / (t - b), o], // (Ps)y
+        [o, o, f / (n - f), (n * f) / (n - f)],       // (Ps)z
+        [o, o, -K::one(), o],                         // (Ps)w
     ])
 }
@@ -0,0 +1,187 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::scalar::{Field, Ring, Semiring};
+
+// element of the prime field Z/pZ, the value is kept reduced in [0, P)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Zp<const P: u64>(u64);
+
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+const fn pow_mod(mut a: u64, mut e: u64, m: u64) -> u64 {
+    let mut ret = 1 % m;
+    while e > 0 {
+        if e & 1 == 1 {
+            ret = mul_mod(ret, a, m);
+        }
+        a = mul_mod(a, a, m);
+        e >>= 1;
+    }
+    ret
+}
+
+// Miller-Rabin, the first twelve primes as witnesses are deterministic for
+// every 64 bit integer
+const fn is_prime(p: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if p < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if p.is_multiple_of(WITNESSES[i]) {
+            return p == WITNESSES[i];
+        }
+        i += 1;
+    }
+
+    let (mut d, mut s) = (p - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+
+    let mut i = 0;
+    'witness: while i < WITNESSES.len() {
+        let mut x = pow_mod(WITNESSES[i], d, p);
+        i += 1;
+        if x == 1 || x == p - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, p);
+            if x == p - 1 {
+                continue 'witness;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+impl<const P: u64> Zp<P> {
+    // evaluated at compile time for every modulus in use
+    const MODULUS: u64 = {
+        assert!(is_prime(P), "modulus must be prime");
+        P
+    };
+
+    pub fn new(v: i64) -> Self {
+        Zp((v as i128).rem_euclid(Self::MODULUS as i128) as u64)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn modulus() -> u64 {
+        P
+    }
+
+    pub fn pow(self, e: u64) -> Self {
+        Zp(pow_mod(self.0, e, P))
+    }
+}
+
+impl<const P: u64> From<u64> for Zp<P> {
+    fn from(value: u64) -> Self {
+        Zp(value % Self::MODULUS)
+    }
+}
+
+impl<const P: u64> From<i64> for Zp<P> {
+    fn from(value: i64) -> Self {
+        Zp::new(value)
+    }
+}
+
+impl<const P: u64> Debug for Zp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Display for Zp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Semiring for Zp<P> {
+    fn one() -> Self {
+        Zp::from(1u64)
+    }
+
+    const EXACT: bool = true;
+}
+
+impl<const P: u64> Ring for Zp<P> {}
+
+impl<const P: u64> Field for Zp<P> {
+    // Fermat's little theorem, a^(p-2) = a^-1
+    fn inv(self) -> Self {
+        assert!(self.0 != 0, "division by zero");
+        self.pow(P - 2)
+    }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Zp(if self.0 == 0 { 0 } else { P - self.0 })
+    }
+}
+
+impl<const P: u64> Add for Zp<P> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Zp(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Zp<P> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for Zp<P> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Zp(mul_mod(self.0, rhs.0, P))
+    }
+}
+
+impl<const P: u64> Div for Zp<P> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+macro_rules! assign {
+    ($tr:ident, $f:ident, $op:tt) => {
+        impl<const P: u64> $tr for Zp<P> {
+            fn $f(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+assign!(AddAssign, add_assign, +);
+assign!(SubAssign, sub_assign, -);
+assign!(MulAssign, mul_assign, *);
+assign!(DivAssign, div_assign, /);
@@ -0,0 +1,222 @@
+use crate::{integer::Int, matrix::Layout, Matrix};
+
+impl<T: Int> Matrix<T> {
+    // H = U A with U unimodular and H in row echelon form, every pivot is
+    // positive and the entries above it lie in [0, pivot). `None` when an
+    // intermediate value overflows
+    pub fn checked_hermite_normal_form(
+        &self,
+    ) -> Option<(Matrix<T>, Matrix<T>)> {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut h = self.to_layout(Layout::RowMajor);
+        let mut u = unit(rows);
+        let mut r = 0;
+
+        for c in 0..cols {
+            if r == rows {
+                break;
+            }
+            for i in r + 1..rows {
+                if h[(i, c)] != T::zero() {
+                    let coef = gcd_step(&h[(r, c)], &h[(i, c)])?;
+                    combine_rows(&mut h, r, i, &coef)?;
+                    combine_rows(&mut u, r, i, &coef)?;
+                }
+            }
+            if h[(r, c)] == T::zero() {
+                continue;
+            }
+            if h[(r, c)] < T::zero() {
+                negate_row(&mut h, r)?;
+                negate_row(&mut u, r)?;
+            }
+
+            for i in 0..r {
+                let q = h[(i, c)].div_euclid(&h[(r, c)]);
+                if q != T::zero() {
+                    let coef =
+                        [T::one(), q.checked_neg()?, T::zero(), T::one()];
+                    combine_rows(&mut h, i, r, &coef)?;
+                    combine_rows(&mut u, i, r, &coef)?;
+                }
+            }
+            r += 1;
+        }
+
+        Some((h, u))
+    }
+
+    // D = U A V with U and V unimodular and D diagonal, the diagonal is non
+    // negative and every entry divides the next one. `None` when an
+    // intermediate value overflows
+    pub fn checked_smith_normal_form(
+        &self,
+    ) -> Option<(Matrix<T>, Matrix<T>, Matrix<T>)> {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut d = self.to_layout(Layout::RowMajor);
+        let (mut u, mut v) = (unit(rows), unit(cols));
+
+        for t in 0..rows.min(cols) {
+            // the smallest entry left makes the best pivot
+            let mut pivot = None;
+            for i in t..rows {
+                for j in t..cols {
+                    let a = abs(&d[(i, j)])?;
+                    if a != T::zero()
+                        && pivot.as_ref().is_none_or(|(p, _, _)| a < *p)
+                    {
+                        pivot = Some((a, i, j));
+                    }
+                }
+            }
+            let Some((_, pi, pj)) = pivot else {
+                break;
+            };
+            swap_rows(&mut d, t, pi);
+            swap_rows(&mut u, t, pi);
+            swap_cols(&mut d, t, pj);
+            swap_cols(&mut v, t, pj);
+
+            loop {
+                for i in t + 1..rows {
+                    if d[(i, t)] != T::zero() {
+                        let coef = gcd_step(&d[(t, t)], &d[(i, t)])?;
+                        combine_rows(&mut d, t, i, &coef)?;
+                        combine_rows(&mut u, t, i, &coef)?;
+                    }
+                }
+                for j in t + 1..cols {
+                    if d[(t, j)] != T::zero() {
+                        let coef = gcd_step(&d[(t, t)], &d[(t, j)])?;
+                        combine_cols(&mut d, t, j, &coef)?;
+                        combine_cols(&mut v, t, j, &coef)?;
+                    }
+                }
+                // the column operations can fill the column again
+                if (t + 1..rows).any(|i| d[(i, t)] != T::zero()) {
+                    continue;
+                }
+
+                // a pivot not dividing the rest is replaced by a gcd, adding
+                // the offending row brings it into the pivot row
+                let p = d[(t, t)].clone();
+                let bad = (t + 1..rows).find(|&i| {
+                    (t + 1..cols).any(|j| {
+                        d[(i, j)]
+                            .checked_rem(&p)
+                            .is_some_and(|r| r != T::zero())
+                    })
+                });
+                let Some(i) = bad else {
+                    break;
+                };
+                let coef = [T::one(), T::one(), T::zero(), T::one()];
+                combine_rows(&mut d, t, i, &coef)?;
+                combine_rows(&mut u, t, i, &coef)?;
+            }
+
+            if d[(t, t)] < T::zero() {
+                negate_row(&mut d, t)?;
+                negate_row(&mut u, t)?;
+            }
+        }
+
+        Some((d, u, v))
+    }
+}
+
+fn unit<T: Int>(n: usize) -> Matrix<T> {
+    let mut ret = Matrix::new(n, n, vec![T::zero(); n * n]);
+    for i in 0..n {
+        ret[(i, i)] = T::one();
+    }
+    ret
+}
+
+fn abs<T: Int>(v: &T) -> Option<T> {
+    if *v < T::zero() {
+        v.checked_neg()
+    } else {
+        Some(v.clone())
+    }
+}
+
+// [x, y, -b / g, a / g] with x a + y b = g = gcd(a, b), as a 2x2 matrix it
+// has determinant one and sends (a, b) to (g, 0)
+fn gcd_step<T: Int>(a: &T, b: &T) -> Option<[T; 4]> {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (T::one(), T::zero());
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    while r1 != T::zero() {
+        let q = r0.checked_div(&r1)?;
+        let r = r0.checked_sub(&q.checked_mul(&r1)?)?;
+        let s = s0.checked_sub(&q.checked_mul(&s1)?)?;
+        let t = t0.checked_sub(&q.checked_mul(&t1)?)?;
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+        (t0, t1) = (t1, t);
+    }
+    if r0 < T::zero() {
+        r0 = r0.checked_neg()?;
+        s0 = s0.checked_neg()?;
+        t0 = t0.checked_neg()?;
+    }
+
+    let a = a.checked_div(&r0)?;
+    let b = b.checked_div(&r0)?;
+    Some([s0, t0, b.checked_neg()?, a])
+}
+
+// (x, y) <- (c0 x + c1 y, c2 x + c3 y)
+fn combine<T: Int>(x: &T, y: &T, coef: &[T; 4]) -> Option<(T, T)> {
+    let nx = coef[0]
+        .checked_mul(x)?
+        .checked_add(&coef[1].checked_mul(y)?)?;
+    let ny = coef[2]
+        .checked_mul(x)?
+        .checked_add(&coef[3].checked_mul(y)?)?;
+    Some((nx, ny))
+}
+
+fn combine_rows<T: Int>(
+    m: &mut Matrix<T>,
+    i: usize,
+    j: usize,
+    coef: &[T; 4],
+) -> Option<()> {
+    for k in 0..m.cols {
+        (m[(i, k)], m[(j, k)]) = combine(&m[(i, k)], &m[(j, k)], coef)?;
+    }
+    Some(())
+}
+
+fn combine_cols<T: Int>(
+    m: &mut Matrix<T>,
+    i: usize,
+    j: usize,
+    coef: &[T; 4],
+) -> Option<()> {
+    for k in 0..m.rows {
+        (m[(k, i)], m[(k, j)]) = combine(&m[(k, i)], &m[(k, j)], coef)?;
+    }
+    Some(())
+}
+
+fn negate_row<T: Int>(m: &mut Matrix<T>, i: usize) -> Option<()> {
+    for k in 0..m.cols {
+        m[(i, k)] = m[(i, k)].checked_neg()?;
+    }
+    Some(())
+}
+
+fn swap_rows<T>(m: &mut Matrix<T>, i: usize, j: usize) {
+    for k in 0..m.cols {
+        m._d.swap(i * m.cols + k, j * m.cols + k);
+    }
+}
+
+fn swap_cols<T>(m: &mut Matrix<T>, i: usize, j: usize) {
+    for k in 0..m.rows {
+        m._d.swap(k * m.cols + i, k * m.cols + j);
+    }
+}
@@ -0,0 +1,409 @@
+use std::{
+    fmt::Debug,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub,
+        SubAssign,
+    },
+};
+
+use crate::{
+    scalar::{ComplexField, Field, Float, Ring, Semiring, Sqrt},
+    utils::epsilon,
+    Complex, Matrix,
+};
+
+// c0 + c1 x + c2 x² + ..., the coefficients are kept without trailing exact
+// zeros so the zero polynomial has none and the derived equality compares
+// values. Tiny float coefficients are kept, they can carry large roots
+#[derive(Clone, PartialEq)]
+pub struct Polynomial<K> {
+    coeffs: Vec<K>,
+}
+
+impl<K: Semiring> Polynomial<K> {
+    // coefficients from the constant term up
+    pub fn new(coeffs: Vec<K>) -> Self {
+        let mut ret = Polynomial { coeffs };
+        ret.trim();
+        ret
+    }
+
+    pub fn constant(c: K) -> Self {
+        Polynomial::new(vec![c])
+    }
+
+    // c xⁿ
+    pub fn monomial(c: K, n: usize) -> Self {
+        let mut coeffs = vec![K::default(); n];
+        coeffs.push(c);
+        Polynomial::new(coeffs)
+    }
+
+    pub fn x() -> Self {
+        Polynomial::monomial(K::one(), 1)
+    }
+
+    pub fn coeffs(&self) -> &[K] {
+        &self.coeffs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    // `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn leading(&self) -> K {
+        self.coeffs.last().cloned().unwrap_or_default()
+    }
+
+    // Horner's scheme
+    pub fn eval(&self, x: K) -> K {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(K::default(), |a, c| a * x.clone() + c.clone())
+    }
+
+    pub fn scale(&self, k: K) -> Self {
+        Polynomial::new(
+            self.coeffs.iter().map(|c| c.clone() * k.clone()).collect(),
+        )
+    }
+
+    pub fn derivative(&self) -> Self {
+        let mut n = K::default();
+        let mut coeffs = Vec::with_capacity(self.coeffs.len());
+        for c in self.coeffs.iter().skip(1) {
+            n += K::one();
+            coeffs.push(c.clone() * n.clone());
+        }
+        Polynomial::new(coeffs)
+    }
+
+    // p(A) by Horner's scheme
+    pub fn eval_mat(&self, a: &Matrix<K>) -> Matrix<K> {
+        assert!(a.is_square(), "matrix must be squared");
+
+        let n = a.rows;
+        let mut ret = Matrix::new(n, n, vec![K::default(); n * n]);
+        for c in self.coeffs.iter().rev() {
+            ret = ret.mul_mat(a);
+            for i in 0..n {
+                ret[(i, i)] += c.clone();
+            }
+        }
+        ret
+    }
+
+    fn trim(&mut self) {
+        while self.coeffs.last().is_some_and(|c| *c == K::default()) {
+            self.coeffs.pop();
+        }
+    }
+
+    fn trim_negligible(&mut self) {
+        while self.coeffs.last().is_some_and(|c| !c.is_non_zero()) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+impl<K: Field> Polynomial<K> {
+    // long division, `self = q * d + r` with deg r < deg d
+    pub fn div_rem(&self, d: &Self) -> (Self, Self) {
+        assert!(!d.is_zero(), "division by zero polynomial");
+
+        let (n, m) = (self.coeffs.len(), d.coeffs.len());
+        if n < m {
+            return (Polynomial::default(), self.clone());
+        }
+
+        let lead = d.leading().inv();
+        let mut r = self.coeffs.clone();
+        let mut q = vec![K::default(); n - m + 1];
+        for i in (0..q.len()).rev() {
+            let c = r[i + m - 1].clone() * lead.clone();
+            for (j, dc) in d.coeffs.iter().enumerate() {
+                r[i + j] -= c.clone() * dc.clone();
+            }
+            q[i] = c;
+        }
+        r.truncate(m - 1);
+        (Polynomial::new(q), Polynomial::new(r))
+    }
+
+    // scaled so the leading coefficient is one
+    pub fn monic(&self) -> Self {
+        match self.is_zero() {
+            true => self.clone(),
+            false => self.scale(self.leading().inv()),
+        }
+    }
+
+    // monic greatest common divisor, by Euclid's algorithm. Remainders
+    // drop the leading coefficients that `is_non_zero` rejects, like the
+    // pivots of `row_echelon`, so float round-off ends the iteration
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        b.trim_negligible();
+        while !b.is_zero() {
+            let mut r = a.div_rem(&b).1;
+            r.trim_negligible();
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+}
+
+impl<T: Float> Polynomial<T> {
+    pub fn roots(&self) -> Vec<Complex<T>> {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|&c| Complex::from([c, T::default()]));
+        Polynomial::new(coeffs.collect()).roots()
+    }
+}
+
+impl<T: Float> Polynomial<Complex<T>> {
+    // eigenvalues of the companion matrix, with multiplicity
+    pub fn roots(&self) -> Vec<Complex<T>> {
+        // x = 0 is an exact root for every vanishing low coefficient
+        let zero = Complex::default();
+        let zeros = self.coeffs.iter().take_while(|&&c| c == zero).count();
+        let p = Polynomial::new(self.coeffs[zeros..].to_vec()).monic();
+        let n = p.degree().unwrap_or(0);
+
+        // upper Hessenberg companion matrix, -c_{n-1} .. -c_0 on the first row
+        let mut h = vec![Complex::default(); n * n];
+        for (j, v) in h.iter_mut().take(n).enumerate() {
+            *v = -p.coeffs[n - 1 - j];
+        }
+        for i in 1..n {
+            h[i * n + i - 1] = Complex::one();
+        }
+
+        let mut ret = vec![Complex::default(); zeros];
+        ret.extend(hessenberg_eigenvalues(h, n));
+        ret
+    }
+}
+
+// Givens rotation [c s; -s̄ c] with c real that zeroes b in [a; b]
+fn givens<T: Float>(a: Complex<T>, b: Complex<T>) -> (T, Complex<T>) {
+    let r = a.hypot(b);
+    if r == T::default() {
+        return (T::one(), Complex::default());
+    }
+    if a == Complex::default() {
+        return (T::default(), b.conj() * (T::one() / b.abs()));
+    }
+    let na = a.abs();
+    (na / r, a * (T::one() / na) * b.conj() * (T::one() / r))
+}
+
+// shifted QR iteration on an upper Hessenberg matrix, Wilkinson shifts
+// deflate one eigenvalue at a time from the bottom
+fn hessenberg_eigenvalues<T: Float>(
+    mut h: Vec<Complex<T>>,
+    n: usize,
+) -> Vec<Complex<T>> {
+    let eps = epsilon::<T>();
+    let two = T::one() + T::one();
+    let mut ret = Vec::with_capacity(n);
+    let mut rot = Vec::with_capacity(n);
+    let (mut hi, mut iter) = (n, 0);
+
+    while hi > 0 {
+        let e = hi - 1;
+
+        // split at the lowest negligible subdiagonal entry
+        let mut l = e;
+        while l > 0 {
+            let s = h[(l - 1) * n + l - 1].abs() + h[l * n + l].abs();
+            if h[l * n + l - 1].abs() <= eps * s {
+                h[l * n + l - 1] = Complex::default();
+                break;
+            }
+            l -= 1;
+        }
+        if l == e || iter == 60 * n {
+            ret.push(h[e * n + e]);
+            (hi, iter) = (e, 0);
+            continue;
+        }
+        iter += 1;
+
+        // eigenvalue of the trailing 2x2 block closest to its last entry, an
+        // exceptional shift now and then breaks cycles
+        let (a, b) = (h[(e - 1) * n + e - 1], h[(e - 1) * n + e]);
+        let (c, d) = (h[e * n + e - 1], h[e * n + e]);
+        let mu = if iter % 11 == 0 {
+            d + Complex::from([c.abs(), T::default()])
+        } else {
+            let m = (a - d) * Complex::from([T::one() / two, T::default()]);
+            let disc = (m * m + b * c).sqrt();
+            let (s1, s2) = (d + m + disc, d + m - disc);
+            if (s1 - d).abs() < (s2 - d).abs() {
+                s1
+            } else {
+                s2
+            }
+        };
+
+        for k in l..=e {
+            h[k * n + k] -= mu;
+        }
+        rot.clear();
+        for k in l..e {
+            let (cs, sn) = givens(h[k * n + k], h[(k + 1) * n + k]);
+            for j in k..=e {
+                let (x, y) = (h[k * n + j], h[(k + 1) * n + j]);
+                h[k * n + j] = x * cs + sn * y;
+                h[(k + 1) * n + j] = y * cs - sn.conj() * x;
+            }
+            rot.push((cs, sn));
+        }
+        for (k, &(cs, sn)) in (l..e).zip(&rot) {
+            for i in l..=(k + 2).min(e) {
+                let (x, y) = (h[i * n + k], h[i * n + k + 1]);
+                h[i * n + k] = x * cs + y * sn.conj();
+                h[i * n + k + 1] = y * cs - x * sn;
+            }
+        }
+        for k in l..=e {
+            h[k * n + k] += mu;
+        }
+    }
+    ret
+}
+
+impl<K: Semiring> Default for Polynomial<K> {
+    fn default() -> Self {
+        Polynomial { coeffs: vec![] }
+    }
+}
+
+impl<K: Semiring> From<K> for Polynomial<K> {
+    fn from(value: K) -> Self {
+        Polynomial::constant(value)
+    }
+}
+
+impl<K: Semiring> Debug for Polynomial<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        for (i, c) in self.coeffs.iter().enumerate() {
+            if i > 0 {
+                write!(f, " + ")?;
+            }
+            match i {
+                0 => write!(f, "{:?}", c)?,
+                1 => write!(f, "{:?}x", c)?,
+                _ => write!(f, "{:?}x^{}", c, i)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// a polynomial ring over exact coefficients is exact too
+impl<K: Semiring> Semiring for Polynomial<K> {
+    const EXACT: bool = K::EXACT;
+
+    fn one() -> Self {
+        Polynomial::constant(K::one())
+    }
+
+    fn is_non_zero(&self) -> bool {
+        !self.is_zero()
+    }
+}
+
+impl<K: Ring> Ring for Polynomial<K> {}
+
+impl<K: Semiring> Add for Polynomial<K> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        if self.coeffs.len() < rhs.coeffs.len() {
+            self.coeffs.resize(rhs.coeffs.len(), K::default());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            *a += b;
+        }
+        self.trim();
+        self
+    }
+}
+
+impl<K: Ring> Neg for Polynomial<K> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<K: Ring> Sub for Polynomial<K> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<K: Semiring> Mul for Polynomial<K> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::default();
+        }
+
+        let mut coeffs =
+            vec![K::default(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] += a.clone() * b.clone();
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+// quotient of the long division, exact whenever the divisor divides, which
+// is all the fraction-free determinant needs
+impl<K: Field> Div for Polynomial<K> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<K: Field> Rem for Polynomial<K> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).1
+    }
+}
+
+macro_rules! assign {
+    ($tr:ident, $f:ident, $op:tt, $b:ident) => {
+        impl<K: $b> $tr for Polynomial<K> {
+            fn $f(&mut self, rhs: Self) {
+                *self = std::mem::take(self) $op rhs;
+            }
+        }
+    };
+}
+
+assign!(AddAssign, add_assign, +, Semiring);
+assign!(SubAssign, sub_assign, -, Ring);
+assign!(MulAssign, mul_assign, *, Semiring);
+assign!(DivAssign, div_assign, /, Field);
@@ -0,0 +1,382 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    scalar::{Float, Lerp},
+    utils::epsilon,
+    Matrix, Vector, V,
+};
+
+// w + xi + yj + zk, unit quaternions represent rotations of 3D space with q
+// and -q giving the same rotation. The product is not commutative so it is
+// not a `Semiring`
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct Quaternion<T = f64> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Float> Debug for Quaternion<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} + {}i + {}j + {}k)", self.w, self.x, self.y, self.z)
+    }
+}
+
+impl<T: Float> Display for Quaternion<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} + {}i + {}j + {}k)", self.w, self.x, self.y, self.z)
+    }
+}
+
+impl<T: Float> From<[T; 4]> for Quaternion<T> {
+    fn from(value: [T; 4]) -> Self {
+        Quaternion::new(value[0], value[1], value[2], value[3])
+    }
+}
+
+impl<T: Float> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        let o = T::default();
+        Quaternion::new(T::one(), o, o, o)
+    }
+
+    // rotation by `angle` radians about `axis`, right handed
+    pub fn from_axis_angle(axis: &Vector<T>, angle: T) -> Self {
+        assert!(axis.size() == 3, "vector must be of size 3");
+
+        let n = axis.norm();
+        assert!(n != T::default(), "vector must be non-zero");
+
+        let half = angle / T::from_f32(2.);
+        let s = half.sin() / n;
+        Quaternion::new(half.cos(), axis[0] * s, axis[1] * s, axis[2] * s)
+    }
+
+    // unit axis and angle in [0, 2 pi), the x axis for the identity
+    pub fn to_axis_angle(&self) -> (Vector<T>, T) {
+        let q = self.normalize();
+        let n = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        let angle = T::from_f32(2.) * n.atan2(q.w);
+        if n == T::default() {
+            let o = T::default();
+            return (V!([T::one(), o, o]), angle);
+        }
+        (V!([q.x / n, q.y / n, q.z / n]), angle)
+    }
+
+    // yaw about z, then pitch about the new y, then roll about the new x,
+    // the same rotation as Rz(yaw) Ry(pitch) Rx(roll)
+    pub fn from_euler(roll: T, pitch: T, yaw: T) -> Self {
+        let two = T::from_f32(2.);
+        let (sr, cr) = ((roll / two).sin(), (roll / two).cos());
+        let (sp, cp) = ((pitch / two).sin(), (pitch / two).cos());
+        let (sy, cy) = ((yaw / two).sin(), (yaw / two).cos());
+
+        Quaternion::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        )
+    }
+
+    // (roll, pitch, yaw) as taken by `from_euler`, pitch is in
+    // [-pi/2, pi/2]
+    pub fn to_euler(&self) -> (T, T, T) {
+        let q = self.normalize();
+        let (o, l, two) = (T::default(), T::one(), T::from_f32(2.));
+
+        let sinp = two * (q.w * q.y - q.z * q.x);
+        let sinp = if sinp > l {
+            l
+        } else if sinp < -l {
+            -l
+        } else {
+            sinp
+        };
+        if l - sinp.abs() <= T::from_f32(4.) * epsilon() {
+            // Ry(+-pi/2) Rx(r) = Rz(-+r) Ry(+-pi/2), only yaw -+ roll is
+            // defined so the roll is reported as zero. Only a pitch within
+            // rounding of +-pi/2 gets here, the general formula below stays
+            // accurate right up to it
+            let (s, pitch) = if sinp > o {
+                (-l, l.asin())
+            } else {
+                (l, (-l).asin())
+            };
+            return (o, pitch, two * (s * q.x).atan2(q.w));
+        }
+
+        let pitch = sinp.asin();
+        let roll = (two * (q.w * q.x + q.y * q.z))
+            .atan2(l - two * (q.x * q.x + q.y * q.y));
+        let yaw = (two * (q.w * q.z + q.x * q.y))
+            .atan2(l - two * (q.y * q.y + q.z * q.z));
+        (roll, pitch, yaw)
+    }
+
+    // accepts a 3x3 rotation matrix or a 4x4 homogeneous transform, the
+    // largest of the four candidate components is computed first to avoid
+    // cancellation (Shepperd)
+    pub fn from_rotation_matrix(m: &Matrix<T>) -> Self {
+        let shape = m.shape();
+        assert!(
+            shape == (3, 3) || shape == (4, 4),
+            "matrix must be 3x3 or 4x4"
+        );
+
+        let (l, four) = (T::one(), T::from_f32(4.));
+        let r = |i: usize, j: usize| m[(i, j)];
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+
+        let q = if trace > T::default() {
+            let s = (trace + l).sqrt() * T::from_f32(2.);
+            Quaternion::new(
+                s / four,
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+            )
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = (l + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * T::from_f32(2.);
+            Quaternion::new(
+                (r(2, 1) - r(1, 2)) / s,
+                s / four,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+            )
+        } else if r(1, 1) > r(2, 2) {
+            let s = (l + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * T::from_f32(2.);
+            Quaternion::new(
+                (r(0, 2) - r(2, 0)) / s,
+                (r(0, 1) + r(1, 0)) / s,
+                s / four,
+                (r(1, 2) + r(2, 1)) / s,
+            )
+        } else {
+            let s = (l + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * T::from_f32(2.);
+            Quaternion::new(
+                (r(1, 0) - r(0, 1)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                s / four,
+            )
+        };
+        q.normalize()
+    }
+
+    // the matrix R with R v = q v q* for column vectors
+    pub fn to_matrix3(&self) -> Matrix<T> {
+        let q = self.normalize();
+        let (l, two) = (T::one(), T::from_f32(2.));
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+
+        Matrix::from([
+            [
+                l - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                l - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                l - two * (x * x + y * y),
+            ],
+        ])
+    }
+
+    // homogeneous transform without translation
+    pub fn to_matrix4(&self) -> Matrix<T> {
+        let r = self.to_matrix3();
+        let (o, l) = (T::default(), T::one());
+
+        let mut vec = Vec::with_capacity(16);
+        for i in 0..3 {
+            vec.extend([r[(i, 0)], r[(i, 1)], r[(i, 2)], o]);
+        }
+        vec.extend([o, o, o, l]);
+        Matrix::new(4, 4, vec)
+    }
+
+    pub fn conj(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn dot(&self, q: &Self) -> T {
+        self.w * q.w + self.x * q.x + self.y * q.y + self.z * q.z
+    }
+
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn inverse(&self) -> Self {
+        // an exact test, the squared norm of a short quaternion can fall
+        // under the `is_non_zero` threshold
+        let n = self.dot(self);
+        assert!(n != T::default(), "quaternion must be non-zero");
+
+        self.conj() * (T::one() / n)
+    }
+
+    pub fn normalize(&self) -> Self {
+        let n = self.norm();
+        assert!(n != T::default(), "quaternion must be non-zero");
+
+        *self * (T::one() / n)
+    }
+
+    // q v q*, expanded so no quaternion product is formed
+    pub fn rotate(&self, v: &Vector<T>) -> Vector<T> {
+        assert!(v.size() == 3, "vector must be of size 3");
+
+        let q = self.normalize();
+        let two = T::from_f32(2.);
+        let cross = |a: [T; 3], b: [T; 3]| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+
+        let u = [q.x, q.y, q.z];
+        let c = cross(u, [v[0], v[1], v[2]]);
+        let t = [two * c[0], two * c[1], two * c[2]];
+        let c = cross(u, t);
+        V!([
+            v[0] + q.w * t[0] + c[0],
+            v[1] + q.w * t[1] + c[1],
+            v[2] + q.w * t[2] + c[2]
+        ])
+    }
+
+    // normalized linear interpolation along the shorter arc, cheaper than
+    // `slerp` but not at constant angular speed
+    pub fn nlerp(a: Self, b: Self, t: T) -> Self {
+        let b = if a.dot(&b) < T::default() { -b } else { b };
+        (a * (T::one() - t) + b * t).normalize()
+    }
+
+    // spherical linear interpolation along the shorter arc at constant
+    // angular speed, nearly equal inputs fall back to `nlerp`
+    pub fn slerp(a: Self, b: Self, t: T) -> Self {
+        let (a, b) = (a.normalize(), b.normalize());
+        let mut cos = a.dot(&b);
+        let b = if cos < T::default() {
+            cos = -cos;
+            -b
+        } else {
+            b
+        };
+
+        if cos > T::from_f32(0.9995) {
+            return Quaternion::nlerp(a, b, t);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let s0 = ((T::one() - t) * theta).sin() / sin;
+        let s1 = (t * theta).sin() / sin;
+        a * s0 + b * s1
+    }
+}
+
+impl<T: Float> Lerp for Quaternion<T> {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => Quaternion::slerp(u, v, T::from_f32(p)),
+        }
+    }
+}
+
+impl<T: Float> Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Float> Add for Quaternion<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl<T: Float> AddAssign for Quaternion<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> Sub for Quaternion<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: Float> SubAssign for Quaternion<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+// Hamilton product, p q rotates by q first and then by p
+impl<T: Float> Mul for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, q: Self) -> Self::Output {
+        let p = self;
+        Quaternion::new(
+            p.w * q.w - p.x * q.x - p.y * q.y - p.z * q.z,
+            p.w * q.x + p.x * q.w + p.y * q.z - p.z * q.y,
+            p.w * q.y - p.x * q.z + p.y * q.w + p.z * q.x,
+            p.w * q.z + p.x * q.y - p.y * q.x + p.z * q.w,
+        )
+    }
+}
+
+impl<T: Float> MulAssign for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Float> Mul<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, a: T) -> Self::Output {
+        Quaternion::new(self.w * a, self.x * a, self.y * a, self.z * a)
+    }
+}
+
+impl<T: Float> MulAssign<T> for Quaternion<T> {
+    fn mul_assign(&mut self, a: T) {
+        *self = *self * a;
+    }
+}
@@ -0,0 +1,468 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use crate::{
+    bigint::BigInt,
+    integer::Int,
+    scalar::{Cast, ComplexField, Field, Lerp, MulAdd, Ring, Semiring, Sqrt},
+};
+
+// largest denominator used when approximating a float by a fraction
+const APPROX_DEN: f64 = (1u64 << 31) as f64;
+
+// an exact fraction kept in lowest terms with a positive denominator, so
+// the derived equality is value equality
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i64> {
+    num: T,
+    den: T,
+}
+
+pub type Rational64 = Rational<i64>;
+pub type Rational128 = Rational<i128>;
+pub type BigRational = Rational<BigInt>;
+
+fn gcd<T: Int>(a: &T, b: &T) -> T {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while b != T::zero() {
+        // `MIN % -1` overflows but is 0
+        let r = a.checked_rem(&b).unwrap_or_else(T::zero);
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl<T: Int> Rational<T> {
+    pub fn new(num: T, den: T) -> Self {
+        Self::checked_new(num, den).expect("rational overflow")
+    }
+
+    pub fn checked_new(num: T, den: T) -> Option<Self> {
+        assert_ne!(den, T::zero(), "denominator must be non-zero");
+
+        if num == T::zero() {
+            return Some(Rational::default());
+        }
+
+        let g = gcd(&num, &den);
+        let (mut num, mut den) = (num.checked_div(&g)?, den.checked_div(&g)?);
+        if den < T::zero() {
+            num = num.checked_neg()?;
+            den = den.checked_neg()?;
+        }
+
+        Some(Rational { num, den })
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.num
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == T::one()
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let (n, d) = (self.num.to_f64(), self.den.to_f64());
+        if n.is_finite() && d.is_finite() {
+            return n / d;
+        }
+
+        // both parts are huge, scale them down to a common exponent first
+        let q = self.num.div_euclid(&self.den);
+        let r = Rational {
+            num: self.num.rem_euclid(&self.den),
+            den: self.den.clone(),
+        };
+        let mut frac = 0.;
+        let mut r = r;
+        let mut scale = 1.;
+        let two32 = T::from_f64(4294967296.).unwrap();
+        for _ in 0..3 {
+            let num = r.num.checked_mul(&two32).unwrap();
+            let digit = num.div_euclid(&r.den);
+            scale /= 4294967296.;
+            frac += digit.to_f64() * scale;
+            r.num = num.rem_euclid(&r.den);
+        }
+        q.to_f64() + frac
+    }
+
+    // best approximation through continued fractions with a denominator of
+    // at most 2^31
+    pub fn from_f64(v: f64) -> Self {
+        assert!(v.is_finite(), "value must be finite");
+
+        let (mut h, mut h1) = (T::one(), T::zero());
+        let (mut k, mut k1) = (T::zero(), T::one());
+        let mut r = v;
+
+        for _ in 0..64 {
+            let a = r.floor();
+            let next = T::from_f64(a).and_then(|a| {
+                let nh = a.checked_mul(&h)?.checked_add(&h1)?;
+                let nk = a.checked_mul(&k)?.checked_add(&k1)?;
+                Some((nh, nk))
+            });
+
+            match next {
+                Some((nh, nk)) if nk.to_f64() <= APPROX_DEN => {
+                    h1 = std::mem::replace(&mut h, nh);
+                    k1 = std::mem::replace(&mut k, nk);
+                }
+                _ => break,
+            }
+
+            let frac = r - a;
+            if frac == 0. {
+                break;
+            }
+            r = 1. / frac;
+        }
+
+        assert_ne!(k, T::zero(), "rational overflow");
+        Rational::new(h, k)
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(&self.den, &rhs.den);
+        let (l, r) = (rhs.den.checked_div(&g)?, self.den.checked_div(&g)?);
+        let num = self
+            .num
+            .checked_mul(&l)?
+            .checked_add(&rhs.num.checked_mul(&r)?)?;
+        Self::checked_new(num, self.den.checked_mul(&l)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cross reduction keeps the intermediate products small
+        let g1 = gcd(&self.num, &rhs.den);
+        let g2 = gcd(&rhs.num, &self.den);
+        let num = (self.num.checked_div(&g1)?)
+            .checked_mul(&rhs.num.checked_div(&g2)?)?;
+        let den = (self.den.checked_div(&g2)?)
+            .checked_mul(&rhs.den.checked_div(&g1)?)?;
+        Self::checked_new(num, den)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_inv()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Rational {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    pub fn checked_pow(self, e: &T) -> Option<Self> {
+        let (mut base, mut e) = if *e < T::zero() {
+            (self.checked_inv()?, e.checked_neg()?)
+        } else {
+            (self, e.clone())
+        };
+
+        let two = T::one().checked_add(&T::one())?;
+        let mut acc = Self::one();
+        while e > T::zero() {
+            if e.rem_euclid(&two) == T::one() {
+                acc = acc.checked_mul(base.clone())?;
+            }
+            e = e.div_euclid(&two);
+            if e > T::zero() {
+                base = base.clone().checked_mul(base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    fn checked_inv(self) -> Option<Self> {
+        assert_ne!(self.num, T::zero(), "division by zero");
+        Self::checked_new(self.den, self.num)
+    }
+
+    fn approx<F: FnOnce(f64) -> f64>(self, f: F) -> Self {
+        Self::from_f64(f(self.to_f64()))
+    }
+}
+
+impl<T: Int> Default for Rational<T> {
+    fn default() -> Self {
+        Rational {
+            num: T::zero(),
+            den: T::one(),
+        }
+    }
+}
+
+impl<T: Int> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Rational {
+            num: value,
+            den: T::one(),
+        }
+    }
+}
+
+impl<T: Int> Debug for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<T: Int> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == T::one() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl<T: Int> Ord for Rational<T> {
+    // compares the continued fraction expansions, so no product of
+    // numerators and denominators can overflow
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.num.clone(), self.den.clone());
+        let (mut c, mut d) = (other.num.clone(), other.den.clone());
+        let mut flip = false;
+
+        loop {
+            let (q1, q2) = (a.div_euclid(&b), c.div_euclid(&d));
+            let (r1, r2) = (a.rem_euclid(&b), c.rem_euclid(&d));
+
+            let ord = if q1 != q2 {
+                q1.cmp(&q2)
+            } else if r1 == T::zero() || r2 == T::zero() {
+                r1.cmp(&r2)
+            } else {
+                (a, b, c, d) = (b, r1, d, r2);
+                flip = !flip;
+                continue;
+            };
+
+            return if flip { ord.reverse() } else { ord };
+        }
+    }
+}
+
+impl<T: Int> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Int> Semiring for Rational<T> {
+    fn one() -> Self {
+        Rational {
+            num: T::one(),
+            den: T::one(),
+        }
+    }
+
+    fn is_non_zero(&self) -> bool {
+        self.num != T::zero()
+    }
+
+    const EXACT: bool = true;
+}
+
+impl<T: Int> Ring for Rational<T> {}
+
+impl<T: Int> Field for Rational<T> {
+    fn inv(self) -> Self {
+        self.checked_inv().expect("rational overflow")
+    }
+}
+
+impl<T: Int> ComplexField for Rational<T> {
+    type AbsOutput = Rational<T>;
+    type TanOutput = Rational<T>;
+    type SinOutput = Rational<T>;
+    type CosOutput = Rational<T>;
+
+    fn abs(&self) -> Self::AbsOutput {
+        if self.num < T::zero() {
+            -self.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    // transcendental functions are not closed over the rationals, they
+    // are evaluated in f64 and rounded back
+    fn tan(self) -> Self::TanOutput {
+        self.approx(f64::tan)
+    }
+
+    fn sin(self) -> Self::SinOutput {
+        self.approx(f64::sin)
+    }
+
+    fn cos(self) -> Self::CosOutput {
+        self.approx(f64::cos)
+    }
+
+    fn sinh(self) -> Self {
+        self.approx(f64::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        self.approx(f64::cosh)
+    }
+
+    fn asin(self) -> Self {
+        self.approx(f64::asin)
+    }
+
+    fn acos(self) -> Self {
+        self.approx(f64::acos)
+    }
+
+    fn atan(self) -> Self {
+        self.approx(f64::atan)
+    }
+
+    fn exp(self) -> Self {
+        self.approx(f64::exp)
+    }
+
+    fn ln(self) -> Self {
+        self.approx(f64::ln)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        if n.is_integer() {
+            self.checked_pow(&n.num).expect("rational overflow")
+        } else {
+            Self::from_f64(self.to_f64().powf(n.to_f64()))
+        }
+    }
+
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+
+    fn hypot(self, other: Self) -> Self::AbsOutput {
+        (self.clone() * self + other.clone() * other).sqrt()
+    }
+
+    fn real(self) -> Self::AbsOutput {
+        self
+    }
+}
+
+fn exact_sqrt<T: Int>(v: &T) -> Option<T> {
+    if *v < T::zero() {
+        return None;
+    }
+    let s = v.isqrt();
+    (s.checked_mul(&s).as_ref() == Some(v)).then_some(s)
+}
+
+impl<T: Int> Sqrt for Rational<T> {
+    // exact for perfect squares, otherwise rounded to 2^-64 for BigInt and
+    // through f64 for i64 and i128, where num den 2^128 always overflows
+    fn sqrt(self) -> Self {
+        assert!(self.num >= T::zero(), "square root of negative value");
+
+        if let (Some(num), Some(den)) =
+            (exact_sqrt(&self.num), exact_sqrt(&self.den))
+        {
+            return Rational { num, den };
+        }
+
+        let scaled = T::from_f64(18446744073709551616.).and_then(|k| {
+            let v = self.num.checked_mul(&self.den)?.checked_mul(&k)?;
+            let root = v.checked_mul(&k)?.isqrt();
+            Self::checked_new(root, self.den.checked_mul(&k)?)
+        });
+        scaled.unwrap_or_else(|| self.approx(f64::sqrt))
+    }
+}
+
+impl<T: Int> Cast<Rational<T>> for Rational<T> {
+    fn cast(self) -> Rational<T> {
+        self
+    }
+}
+
+impl<T: Int> Cast<f64> for Rational<T> {
+    fn cast(self) -> f64 {
+        self.to_f64()
+    }
+}
+
+impl<T: Int> Cast<f32> for Rational<T> {
+    fn cast(self) -> f32 {
+        self.to_f64() as f32
+    }
+}
+
+impl<T: Int> Lerp for Rational<T> {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => u.clone() + (v - u) * Self::from_f64(p as f64),
+        }
+    }
+}
+
+impl<T: Int> MulAdd<Rational<T>, Rational<T>> for Rational<T> {
+    fn mul_add(self, a: &Self, b: &Self) -> Self {
+        self * a.clone() + b.clone()
+    }
+}
+
+impl<T: Int> Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Rational::default(), |a, b| a + b)
+    }
+}
+
+impl<T: Int> Neg for Rational<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.checked_neg().expect("rational overflow")
+    }
+}
+
+macro_rules! ops {
+    ($tr:ident, $f:ident, $atr:ident, $af:ident, $checked:ident) => {
+        impl<T: Int> $tr for Rational<T> {
+            type Output = Self;
+            fn $f(self, rhs: Self) -> Self {
+                self.$checked(rhs).expect("rational overflow")
+            }
+        }
+
+        impl<T: Int> $atr for Rational<T> {
+            fn $af(&mut self, rhs: Self) {
+                *self = self.clone().$checked(rhs).expect("rational overflow");
+            }
+        }
+    };
+}
+
+ops!(Add, add, AddAssign, add_assign, checked_add);
+ops!(Sub, sub, SubAssign, sub_assign, checked_sub);
+ops!(Mul, mul, MulAssign, mul_assign, checked_mul);
+ops!(Div, div, DivAssign, div_assign, checked_div);
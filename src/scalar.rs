@@ -1,43 +1,145 @@
-use std::{fmt::Debug, iter::Sum};
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
 
-pub trait Scalar:
+// `+` and `*` with their identities, enough for `mul_mat`, `trace` and
+// `transpose`, `Default` is the additive identity
+pub trait Semiring:
     Debug
-    + Copy
+    + Clone
     + Default
-    + std::cmp::PartialOrd
-    + std::ops::Neg<Output = Self>
-    + std::ops::Add<Output = Self>
-    + std::ops::Sub<Output = Self>
-    + std::ops::Mul<Output = Self>
-    + std::ops::Div<Output = Self>
-    + std::ops::AddAssign
-    + std::ops::SubAssign
-    + std::ops::MulAssign
-    + std::ops::DivAssign
-    + std::iter::Sum
-    + MulAdd<Self, Self>
-    + Lerp
+    + PartialEq
+    + Add<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + MulAssign
 {
+    fn one() -> Self;
+
+    fn is_non_zero(&self) -> bool {
+        *self != Self::default()
+    }
+
+    // exact types compute determinants by fraction-free elimination instead
+    // of cofactor expansion, `is_non_zero` must be an exact test for them
+    const EXACT: bool = false;
+
+    // conjugation hook used by `Dot` and `conjugate_transpose`, real types
+    // keep the identity
+    fn conj(self) -> Self {
+        self
+    }
+
+    fn slice_dot(u: &[Self], v: &[Self]) -> Self {
+        let mut sum = Self::default();
+        for (a, b) in u.iter().zip(v) {
+            sum += a.clone() * b.clone().conj();
+        }
+        sum
+    }
+
+    // Σ u_i v_i without conjugation, the inner loop of `mul_vec` and
+    // `mul_mat`
+    fn slice_mul_sum(u: &[Self], v: &[Self]) -> Self {
+        let mut sum = Self::default();
+        for (a, b) in u.iter().zip(v) {
+            sum += a.clone() * b.clone();
+        }
+        sum
+    }
+
+    fn slice_axpy(y: &mut [Self], a: Self, x: &[Self]) {
+        for (y, x) in y.iter_mut().zip(x) {
+            *y += x.clone() * a.clone();
+        }
+    }
+
+    fn slice_scale(x: &mut [Self], a: Self) {
+        for x in x.iter_mut() {
+            *x *= a.clone();
+        }
+    }
+
+    fn slice_add(y: &mut [Self], x: &[Self]) {
+        for (y, x) in y.iter_mut().zip(x) {
+            *y += x.clone();
+        }
+    }
+}
+
+pub trait Ring:
+    Semiring + Neg<Output = Self> + Sub<Output = Self> + SubAssign
+{
+}
+
+pub trait Field: Ring + Div<Output = Self> + DivAssign {
+    fn inv(self) -> Self;
+}
+
+pub trait ComplexField: Field + Sum + MulAdd<Self, Self> + Lerp {
     type AbsOutput: Sum<Self::AbsOutput>
         + Debug
-        + Copy
+        + Clone
         + Default
-        + std::cmp::PartialOrd
-        + std::ops::AddAssign
+        + PartialOrd
+        + AddAssign
         + MulAdd<Self::AbsOutput, Self::AbsOutput>
         + Sqrt
-        + std::ops::Mul<Output = Self::AbsOutput>;
+        + Mul<Output = Self::AbsOutput>
+        + Div<Output = Self::AbsOutput>;
     type TanOutput;
     type CosOutput;
     type SinOutput;
 
     fn abs(&self) -> Self::AbsOutput;
-    fn one() -> Self;
-    fn inv(self) -> Self;
     fn tan(self) -> Self::TanOutput;
     fn sin(self) -> Self::SinOutput;
     fn cos(self) -> Self::CosOutput;
-    fn is_non_zero(&self) -> bool;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self::AbsOutput) -> Self;
+    fn powc(self, n: Self) -> Self;
+    fn hypot(self, other: Self) -> Self::AbsOutput;
+    fn real(self) -> Self::AbsOutput;
+}
+
+pub trait RealField:
+    ComplexField<
+        AbsOutput = Self,
+        TanOutput = Self,
+        SinOutput = Self,
+        CosOutput = Self,
+    > + PartialOrd
+    + Sqrt
+{
+}
+
+impl<T> RealField for T where
+    T: ComplexField<AbsOutput = T, TanOutput = T, SinOutput = T, CosOutput = T>
+        + PartialOrd
+        + Sqrt
+{
+}
+
+// every element type of the crate before the split, kept so generic code
+// written against it still compiles
+pub trait Scalar: ComplexField + PartialOrd {}
+
+impl<T: ComplexField + PartialOrd> Scalar for T {}
+
+// real floating point types usable as the components of a `Complex`
+pub trait Float: RealField + Copy + Display {
+    fn atan2(self, other: Self) -> Self;
+    fn from_f32(v: f32) -> Self;
 }
 
 pub trait MulAdd<U, V> {
@@ -48,8 +150,18 @@ pub trait Sqrt {
     fn sqrt(self) -> Self;
 }
 
-pub trait Lerp {
+pub trait Cast<U> {
+    fn cast(self) -> U;
+}
+
+pub trait Lerp: Sized {
     fn lerp(u: Self, v: Self, t: f32) -> Self;
+
+    // element step of the `Vector`, `Matrix` and lazy lerps, floating point
+    // types fuse it into a single rounding
+    fn lerp_elem(u: Self, v: Self, t: f32) -> Self {
+        Self::lerp(u, v, t)
+    }
 }
 
 pub fn lerp<V: Lerp>(u: V, v: V, t: f32) -> V {
@@ -0,0 +1,115 @@
+use crate::{matrix::Layout, scalar::Ring, Matrix};
+
+impl<K: Ring> Matrix<K> {
+    pub fn mul_mat_strassen(
+        &self,
+        mat: &Matrix<K>,
+        cutoff: usize,
+    ) -> Matrix<K> {
+        assert_eq!(
+            self.cols, mat.rows,
+            "bad input for matrix and matrix multiplication"
+        );
+
+        strassen(
+            &self.in_layout(Layout::RowMajor),
+            &mat.in_layout(Layout::RowMajor),
+            cutoff.max(1),
+        )
+    }
+
+    fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> Self {
+        let mut vec = Vec::with_capacity(rows * cols);
+        for i in row..row + rows {
+            vec.extend_from_slice(&self[i][col..col + cols]);
+        }
+
+        Matrix {
+            _d: vec,
+            rows,
+            cols,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    fn set_block(&mut self, row: usize, col: usize, mat: &Matrix<K>) {
+        for i in 0..mat.rows {
+            self[row + i][col..col + mat.cols].clone_from_slice(&mat[i]);
+        }
+    }
+}
+
+fn strassen<K: Ring>(a: &Matrix<K>, b: &Matrix<K>, cutoff: usize) -> Matrix<K> {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    if m <= cutoff || k <= cutoff || n <= cutoff {
+        return a * b;
+    }
+
+    // odd dimensions are peeled: the recursion runs on the largest even
+    // block and the leftover row, column and inner product term are
+    // patched in classically afterwards
+    let (hm, hk, hn) = (m / 2, k / 2, n / 2);
+
+    let a11 = a.block(0, 0, hm, hk);
+    let a12 = a.block(0, hk, hm, hk);
+    let a21 = a.block(hm, 0, hm, hk);
+    let a22 = a.block(hm, hk, hm, hk);
+    let b11 = b.block(0, 0, hk, hn);
+    let b12 = b.block(0, hn, hk, hn);
+    let b21 = b.block(hk, 0, hk, hn);
+    let b22 = b.block(hk, hn, hk, hn);
+
+    let m1 = strassen(
+        &(a11.clone() + a22.clone()),
+        &(b11.clone() + b22.clone()),
+        cutoff,
+    );
+    let m2 = strassen(&(a21.clone() + a22.clone()), &b11, cutoff);
+    let m3 = strassen(&a11, &(b12.clone() - b22.clone()), cutoff);
+    let m4 = strassen(&a22, &(b21.clone() - b11.clone()), cutoff);
+    let m5 = strassen(&(a11.clone() + a12.clone()), &b22, cutoff);
+    let m6 = strassen(&(a21 - a11), &(b11 + b12), cutoff);
+    let m7 = strassen(&(a12 - a22), &(b21 + b22), cutoff);
+
+    let mut c = Matrix {
+        _d: vec![K::default(); m * n],
+        rows: m,
+        cols: n,
+        layout: Layout::RowMajor,
+    };
+    c.set_block(0, 0, &(m1.clone() + m4.clone() - m5.clone() + m7));
+    c.set_block(0, hn, &(m3.clone() + m5));
+    c.set_block(hm, 0, &(m2.clone() + m4));
+    c.set_block(hm, hn, &(m1 - m2 + m3 + m6));
+
+    if k % 2 == 1 {
+        for i in 0..2 * hm {
+            let x = &a[i][k - 1];
+            for j in 0..2 * hn {
+                c[i][j] += x.clone() * b[k - 1][j].clone();
+            }
+        }
+    }
+
+    if n % 2 == 1 {
+        for i in 0..m {
+            let mut val = K::default();
+            for r in 0..k {
+                val += a[i][r].clone() * b[r][n - 1].clone();
+            }
+            c[i][n - 1] = val;
+        }
+    }
+
+    if m % 2 == 1 {
+        for j in 0..n {
+            let mut val = K::default();
+            for r in 0..k {
+                val += a[m - 1][r].clone() * b[r][j].clone();
+            }
+            c[m - 1][j] = val;
+        }
+    }
+
+    c
+}
@@ -1,12 +1,24 @@
+use crate::scalar::Float;
+
 pub const EPSILON: f64 = 1e-10;
 
+// smallest e with 1 + e != 1
+pub(crate) fn epsilon<T: Float>() -> T {
+    let (one, two) = (T::one(), T::one() + T::one());
+    let mut e = one;
+    while one + e / two != one {
+        e /= two;
+    }
+    e
+}
+
 #[macro_export]
 macro_rules! approx_eq {
     ($a: expr, $b: expr) => {
-        ($a - $b).abs() < 1e-6
+        $crate::scalar::ComplexField::abs(&($a - $b)) < 1e-6
     };
 
     ($a: expr, $b: expr, $c: expr) => {
-        ($a - $b).abs() < $c
+        $crate::scalar::ComplexField::abs(&($a - $b)) < $c
     }
 }
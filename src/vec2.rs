@@ -1,4 +1,4 @@
-use crate::{Matrix, Vector};
+use crate::{scalar::Float, Matrix, Vector};
 
 macro_rules! M {
     ($values:expr) => {
@@ -12,32 +12,37 @@ macro_rules! size {
     };
 }
 
-pub fn scale2(v: &Vector<f32>, dx: f32, dy: f32) -> Vector<f32> {
+pub fn scale2<K: Float>(v: &Vector<K>, dx: K, dy: K) -> Vector<K> {
     size!(v);
-    M!([[dx, 0.], [0., dy]]) * v
+    let o = K::default();
+    M!([[dx, o], [o, dy]]) * v
 }
 
-pub fn shear2x(v: &Vector<f32>, s: f32) -> Vector<f32> {
+pub fn shear2x<K: Float>(v: &Vector<K>, s: K) -> Vector<K> {
     size!(v);
-    M!([[1., s], [0., 1.]]) * v
+    let (o, l) = (K::default(), K::one());
+    M!([[l, s], [o, l]]) * v
 }
 
-pub fn shear2y(v: &Vector<f32>, s: f32) -> Vector<f32> {
+pub fn shear2y<K: Float>(v: &Vector<K>, s: K) -> Vector<K> {
     size!(v);
-    M!([[1., 0.], [s, 1.]]) * v
+    let (o, l) = (K::default(), K::one());
+    M!([[l, o], [s, l]]) * v
 }
 
-pub fn rotate2(v: &Vector<f32>, deg: f32) -> Vector<f32> {
+pub fn rotate2<K: Float>(v: &Vector<K>, deg: K) -> Vector<K> {
     size!(v);
     M!([[deg.cos(), -deg.sin()], [deg.sin(), deg.cos()]]) * v
 }
 
-pub fn reflect2x(v: &Vector<f32>) -> Vector<f32> {
+pub fn reflect2x<K: Float>(v: &Vector<K>) -> Vector<K> {
     size!(v);
-    M!([[1., 0.], [0., -1.]]) * v
+    let (o, l) = (K::default(), K::one());
+    M!([[l, o], [o, -l]]) * v
 }
 
-pub fn reflect2y(v: &Vector<f32>) -> Vector<f32> {
+pub fn reflect2y<K: Float>(v: &Vector<K>) -> Vector<K> {
     size!(v);
-    M!([[-1., 0.], [0., 1.]]) * v
+    let (o, l) = (K::default(), K::one());
+    M!([[-l, o], [o, l]]) * v
 }
@@ -1,4 +1,6 @@
-use crate::{Matrix, Vector};
+use crate::{
+    cross_product, scalar::Float, Dot, Matrix, Quaternion, Transpose, Vector,
+};
 
 macro_rules! M {
     ($values:expr) => {
@@ -12,34 +14,138 @@ macro_rules! size {
     };
 }
 
-pub fn scale3(v: &Vector<f32>, dx: f32, dy: f32, dz: f32) -> Vector<f32> {
+macro_rules! square {
+    ($mat:expr) => {
+        assert!($mat.shape() == (3, 3), "matrix must be 3x3");
+    };
+}
+
+pub fn scale3<K: Float>(v: &Vector<K>, dx: K, dy: K, dz: K) -> Vector<K> {
     size!(v);
-    M!([[dx, 0., 0.], [0., dy, 0.], [0., 0., dz]]) * v
+    let o = K::default();
+    M!([[dx, o, o], [o, dy, o], [o, o, dz]]) * v
 }
 
-pub fn rotate3x(v: &Vector<f32>, deg: f32) -> Vector<f32> {
+pub fn rotate3x<K: Float>(v: &Vector<K>, deg: K) -> Vector<K> {
     size!(v);
+    let (o, l) = (K::default(), K::one());
     M!([
-        [1., 0., 0.],
-        [0., deg.cos(), -deg.sin()],
-        [0., deg.sin(), deg.cos()]
+        [l, o, o],
+        [o, deg.cos(), -deg.sin()],
+        [o, deg.sin(), deg.cos()]
     ]) * v
 }
 
-pub fn rotate3y(v: &Vector<f32>, deg: f32) -> Vector<f32> {
+pub fn rotate3y<K: Float>(v: &Vector<K>, deg: K) -> Vector<K> {
     size!(v);
+    let (o, l) = (K::default(), K::one());
     M!([
-        [deg.cos(), 0., deg.sin()],
-        [0., 1., 0.],
-        [-deg.sin(), 0., deg.cos()]
+        [deg.cos(), o, deg.sin()],
+        [o, l, o],
+        [-deg.sin(), o, deg.cos()]
     ]) * v
 }
 
-pub fn rotate3z(v: &Vector<f32>, deg: f32) -> Vector<f32> {
+pub fn rotate3z<K: Float>(v: &Vector<K>, deg: K) -> Vector<K> {
     size!(v);
+    let (o, l) = (K::default(), K::one());
     M!([
-        [deg.cos(), -deg.sin(), 0.],
-        [deg.sin(), deg.cos(), 0.],
-        [0., 0., 1.]
+        [deg.cos(), -deg.sin(), o],
+        [deg.sin(), deg.cos(), o],
+        [o, o, l]
     ]) * v
 }
+
+// Rodrigues' formula, rotation by `angle` about an arbitrary axis:
+// v cos(a) + (k x v) sin(a) + k (k . v) (1 - cos(a)) for the unit axis k
+pub fn rotate3<K: Float>(
+    v: &Vector<K>,
+    axis: &Vector<K>,
+    angle: K,
+) -> Vector<K> {
+    size!(v);
+    size!(axis);
+    let n = axis.norm();
+    assert!(n.is_non_zero(), "vector must be non-zero");
+
+    let k = axis * &(K::one() / n);
+    let (s, c) = (angle.sin(), angle.cos());
+
+    let mut ret = v * &c;
+    K::slice_axpy(&mut ret._d, s, &cross_product(&k, v)._d);
+    K::slice_axpy(&mut ret._d, k.dot(v) * (K::one() - c), &k._d);
+    ret
+}
+
+// R = I + sin(a) [k]x + (1 - cos(a)) [k]x^2 for the unit axis k
+pub fn rotation3<K: Float>(axis: &Vector<K>, angle: K) -> Matrix<K> {
+    size!(axis);
+    let n = axis.norm();
+    assert!(n.is_non_zero(), "vector must be non-zero");
+
+    let (x, y, z) = (axis[0] / n, axis[1] / n, axis[2] / n);
+    let (s, c) = (angle.sin(), angle.cos());
+    let t = K::one() - c;
+    Matrix::from([
+        [c + t * x * x, t * x * y - s * z, t * x * z + s * y],
+        [t * x * y + s * z, c + t * y * y, t * y * z - s * x],
+        [t * x * z - s * y, t * y * z + s * x, c + t * z * z],
+    ])
+}
+
+// R^T R = I within `tol` and det(R) = 1, so no reflection
+pub fn is_rotation3<K: Float>(m: &Matrix<K>, tol: K) -> bool {
+    square!(m);
+    let rtr = m.transpose().mul_mat(m);
+    let orthonormal = (0..3).all(|i| {
+        (0..3).all(|j| {
+            let id = if i == j { K::one() } else { K::default() };
+            (rtr[(i, j)] - id).abs() <= tol
+        })
+    });
+    orthonormal && (m.determinant() - K::one()).abs() <= tol
+}
+
+// nearest rotation in the Frobenius norm to a matrix that drifted from one,
+// the polar factor found by the Newton iteration R <- (R + R^-T) / 2 which
+// converges quadratically
+pub fn reorthonormalize3<K: Float>(m: &Matrix<K>) -> Matrix<K> {
+    square!(m);
+    let half = K::from_f32(0.5);
+    let mut r = m.clone();
+    let mut prev = None;
+    for _ in 0..32 {
+        let inv_t = r.inverse().expect("matrix must be invertible").transpose();
+        let next = (r.clone() + inv_t) * half;
+        let diff = (0..3)
+            .flat_map(|i| (0..3).map(move |j| (i, j)))
+            .map(|(i, j)| (next[(i, j)] - r[(i, j)]).abs())
+            .fold(K::default(), |a, b| if b > a { b } else { a });
+        r = next;
+        // stops once rounding keeps the update from shrinking
+        if prev.is_some_and(|p| diff >= p) || diff == K::default() {
+            break;
+        }
+        prev = Some(diff);
+    }
+    r
+}
+
+// unit axis and angle in [0, pi], the x axis for the identity
+pub fn axis_angle3<K: Float>(m: &Matrix<K>) -> (Vector<K>, K) {
+    square!(m);
+    let (axis, angle) = Quaternion::from_rotation_matrix(m).to_axis_angle();
+    let pi = (-K::one()).acos();
+    if angle > pi {
+        let two = K::from_f32(2.);
+        return (axis * -K::one(), two * pi - angle);
+    }
+    (axis, angle)
+}
+
+// the rotation R with R a = b
+pub fn relative_rotation3<K: Float>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K> {
+    square!(a);
+    square!(b);
+    b.mul_mat(&a.transpose())
+}
@@ -1,13 +1,15 @@
 use std::{
     fmt::Debug,
-    ops::{Add, AddAssign, Index, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::scalar::{MulAdd, Scalar, Sqrt};
+use crate::scalar::{
+    Cast, ComplexField, Field, Float, Lerp, MulAdd, Ring, Semiring, Sqrt,
+};
 
 #[derive(Clone, Default)]
 pub struct Vector<K> {
-    pub _d: Vec<K>,
+    pub(crate) _d: Vec<K>,
 }
 
 pub trait Dot<K> {
@@ -34,7 +36,7 @@ macro_rules! V {
     };
 }
 
-impl<K: Scalar> Debug for Vector<K> {
+impl<K: Debug> Debug for Vector<K> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.debug_list().entries(self._d.iter()).finish()
     }
@@ -62,32 +64,32 @@ impl<K> Index<usize> for Vector<K> {
     }
 }
 
-impl<K: Scalar> Add for Vector<K> {
+impl<K> IndexMut<usize> for Vector<K> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self._d[index]
+    }
+}
+
+impl<K: Semiring> Add for Vector<K> {
     type Output = Self;
 
-    fn add(self, other: Self) -> Self::Output {
+    fn add(mut self, other: Self) -> Self::Output {
         assert_eq!(self.size(), other.size(), "vectors must be the same size");
 
-        let mut vec = Vec::with_capacity(self.size());
-        for i in 0..self.size() {
-            vec.push(self[i] + other[i]);
-        }
-
-        V!(vec)
+        K::slice_add(&mut self._d, &other._d);
+        self
     }
 }
 
-impl<K: Scalar> AddAssign<&Vector<K>> for Vector<K> {
+impl<K: Semiring> AddAssign<&Vector<K>> for Vector<K> {
     fn add_assign(&mut self, rhs: &Vector<K>) {
         assert_eq!(self.size(), rhs.size(), "vectors must be the same size");
 
-        for i in 0..self.size() {
-            self._d[i] += rhs[i];
-        }
+        K::slice_add(&mut self._d, &rhs._d);
     }
 }
 
-impl<K: Scalar> Sub for Vector<K> {
+impl<K: Ring> Sub for Vector<K> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -95,24 +97,24 @@ impl<K: Scalar> Sub for Vector<K> {
 
         let mut vec = Vec::with_capacity(self.size());
         for i in 0..self.size() {
-            vec.push(self[i] - rhs[i]);
+            vec.push(self[i].clone() - rhs[i].clone());
         }
 
         V!(vec)
     }
 }
 
-impl<K: Scalar> SubAssign<&Vector<K>> for Vector<K> {
+impl<K: Ring> SubAssign<&Vector<K>> for Vector<K> {
     fn sub_assign(&mut self, rhs: &Vector<K>) {
         assert_eq!(self.size(), rhs.size(), "vectors must be the same size");
 
         for i in 0..self.size() {
-            self._d[i] -= rhs[i];
+            self._d[i] -= rhs[i].clone();
         }
     }
 }
 
-impl<K: Scalar + Mul<U, Output = K>, U: Scalar> Mul<U> for Vector<K> {
+impl<K: Semiring + Mul<U, Output = K>, U: Semiring> Mul<U> for Vector<K> {
     type Output = Self;
 
     fn mul(self, a: U) -> Self::Output {
@@ -120,29 +122,127 @@ impl<K: Scalar + Mul<U, Output = K>, U: Scalar> Mul<U> for Vector<K> {
     }
 }
 
-impl<K: Scalar + Mul<U, Output = K>, U: Scalar> Mul<&U> for &Vector<K> {
+impl<K: Semiring + Mul<U, Output = K>, U: Semiring> Mul<&U> for &Vector<K> {
     type Output = Vector<K>;
 
-    fn mul(self, &a: &U) -> Self::Output {
+    fn mul(self, a: &U) -> Self::Output {
         let mut vec = Vec::with_capacity(self.size());
 
         for i in 0..self.size() {
-            vec.push(self[i] * a);
+            vec.push(self[i].clone() * a.clone());
         }
 
         V!(vec)
     }
 }
 
-impl<K: Scalar + MulAssign<U>, U: Scalar> MulAssign<&U> for Vector<K> {
+impl<K: Semiring + MulAssign<U>, U: Semiring> MulAssign<&U> for Vector<K> {
     fn mul_assign(&mut self, a: &U) {
         for i in &mut self._d {
-            *i *= *a;
+            *i *= a.clone();
+        }
+    }
+}
+
+impl<K: Semiring> Dot<K> for [K] {
+    fn dot(&self, v: &Vector<K>) -> K {
+        assert_eq!(v.size(), self.len(), "vectors must be the same size");
+
+        self * v
+    }
+}
+
+impl<K: Semiring> Dot<K> for Vector<K> {
+    fn dot(&self, v: &Vector<K>) -> K {
+        assert_eq!(v.size(), self.size(), "vectors must be the same size");
+
+        self * v
+    }
+}
+
+impl<K: Semiring> Mul<&Vector<K>> for &Vector<K> {
+    type Output = K;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        self * &rhs._d
+    }
+}
+
+impl<K: Semiring> Mul<&Vec<K>> for &Vector<K> {
+    type Output = K;
+
+    fn mul(self, rhs: &Vec<K>) -> Self::Output {
+        self * &rhs[..]
+    }
+}
+
+impl<K: Semiring> Mul<&Vector<K>> for &Vec<K> {
+    type Output = K;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        &self[..] * rhs
+    }
+}
+
+impl<K: Semiring> Mul<&[K]> for &Vector<K> {
+    type Output = K;
+
+    fn mul(self, rhs: &[K]) -> Self::Output {
+        K::slice_dot(&self._d, rhs)
+    }
+}
+
+impl<K: Semiring> Mul<&Vector<K>> for &[K] {
+    type Output = K;
+
+    fn mul(self, rhs: &Vector<K>) -> Self::Output {
+        K::slice_dot(self, &rhs._d)
+    }
+}
+
+impl<K: Semiring + MulAdd<U, K>, U: Semiring> MulAdd<U, Vector<K>>
+    for Vector<K>
+{
+    fn mul_add(self, a: &U, b: &Vector<K>) -> Self {
+        assert!(self.size() == b.size(), "vectors must be the same size");
+
+        let mut vec = Vec::with_capacity(self.size());
+
+        for i in 0..self.size() {
+            vec.push(self[i].clone().mul_add(a, &b[i]))
         }
+
+        V!(vec)
+    }
+}
+
+impl<K: ComplexField> Angle for Vector<K> {
+    type Output = K::AbsOutput;
+
+    fn angle_cos(u: &Vector<K>, v: &Vector<K>) -> Self::Output {
+        u.dot(v).real() / (u.norm() * v.norm())
     }
 }
 
-pub fn linear_combination<K: Scalar>(
+impl<K: Lerp + Clone> Lerp for Vector<K> {
+    fn lerp(u: Self, v: Self, t: f32) -> Self {
+        match t {
+            0. => u,
+            1. => v,
+            p => {
+                let mut vec = Vec::with_capacity(u.size());
+
+                for i in 0..u.size() {
+                    vec.push(K::lerp_elem(u[i].clone(), v[i].clone(), p))
+                }
+
+                V!(vec)
+            }
+        }
+    }
+}
+
+pub fn linear_combination<K: Semiring>(
     u: &[&Vector<K>],
     coefs: &[K],
 ) -> Vector<K> {
@@ -159,11 +259,9 @@ pub fn linear_combination<K: Scalar>(
 
     let mut iter = u.iter().zip(coefs);
 
-    if let Some(mut sum) = iter.next().map(|(&v, &k)| v.clone() * k) {
+    if let Some(mut sum) = iter.next().map(|(&v, k)| v.clone() * k.clone()) {
         for (v, k) in iter {
-            for i in 0..sum.size() {
-                sum._d[i] = v[i].mul_add(k, &sum[i]);
-            }
+            K::slice_axpy(&mut sum._d, k.clone(), &v._d);
         }
         sum
     } else {
@@ -171,22 +269,73 @@ pub fn linear_combination<K: Scalar>(
     }
 }
 
-pub fn cross_product<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> Vector<K> {
+// modified Gram-Schmidt, each input loses its components along the vectors
+// found so far before being scaled to unit length. A second projection
+// pass with `reorthogonalize` recovers the orthogonality lost to
+// cancellation. An input whose remainder is at most `tol` times its norm
+// depends on the previous ones, exact types ignore `tol` and test the
+// remainder against zero
+pub fn orthonormalize<K: ComplexField>(
+    u: &[&Vector<K>],
+    reorthogonalize: bool,
+    tol: K::AbsOutput,
+) -> Result<Vec<Vector<K>>, &'static str>
+where
+    K::AbsOutput: Cast<K>,
+{
+    assert!(
+        u.iter().all(|v| v.size() == u[0].size()),
+        "vectors must have the same dimension"
+    );
+
+    let passes = if reorthogonalize { 2 } else { 1 };
+    let mut basis: Vec<Vector<K>> = Vec::with_capacity(u.len());
+    for &v in u {
+        let mut w = v.clone();
+        for _ in 0..passes {
+            for q in &basis {
+                let c = w.dot(q);
+                w = linear_combination(&[&w, q], &[K::one(), -c]);
+            }
+        }
+
+        let norm = w.norm();
+        let independent = if K::EXACT {
+            w.iter().any(|x| x.is_non_zero())
+        } else {
+            norm > tol.clone() * v.norm()
+        };
+        if !independent {
+            return Err("vectors must be linearly independent");
+        }
+
+        let inv = norm.cast().inv();
+        w.scl(inv);
+        basis.push(w);
+    }
+
+    Ok(basis)
+}
+
+pub fn cross_product<K: Ring>(u: &Vector<K>, v: &Vector<K>) -> Vector<K> {
     assert!(
         u.size() == 3 && v.size() == u.size(),
         "vectors must have be of size 3"
     );
 
-    V!([
-        (u[1] * v[2]) - (u[2] * v[1]),
-        (u[2] * v[0]) - (u[0] * v[2]),
-        (u[0] * v[1]) - (u[1] * v[0]),
-    ])
+    let (u, v) = (&u._d, &v._d);
+    let m = |i: usize, j: usize| u[i].clone() * v[j].clone();
+
+    V!([m(1, 2) - m(2, 1), m(2, 0) - m(0, 2), m(0, 1) - m(1, 0)])
 }
 
-impl<K: Scalar> Vector<K> {
-    pub fn zero(size: usize) -> Self {
-        V!(vec![K::default(); size])
+impl<K> Vector<K> {
+    pub fn iter(&self) -> std::slice::Iter<'_, K> {
+        self._d.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, K> {
+        self._d.iter_mut()
     }
 
     pub fn size(&self) -> usize {
@@ -200,19 +349,93 @@ impl<K: Scalar> Vector<K> {
     pub fn is_empty(&self) -> bool {
         self._d.is_empty()
     }
+}
+
+impl<K: Clone> Vector<K> {
+    pub fn map<U, F: FnMut(K) -> U>(&self, f: F) -> Vector<U> {
+        V!(self._d.iter().cloned().map(f).collect::<Vec<_>>())
+    }
+
+    pub fn zip_map<V: Clone, U, F: FnMut(K, V) -> U>(
+        &self,
+        other: &Vector<V>,
+        mut f: F,
+    ) -> Vector<U> {
+        assert_eq!(
+            self._d.len(),
+            other._d.len(),
+            "vectors must be the same size"
+        );
+
+        V!(self
+            ._d
+            .iter()
+            .zip(&other._d)
+            .map(|(a, b)| f(a.clone(), b.clone()))
+            .collect::<Vec<_>>())
+    }
+
+    pub fn fold<B, F: FnMut(B, K) -> B>(&self, init: B, f: F) -> B {
+        self._d.iter().cloned().fold(init, f)
+    }
+
+    pub fn cast<U>(&self) -> Vector<U>
+    where
+        K: Cast<U>,
+    {
+        self.map(K::cast)
+    }
+}
+
+impl<K: Semiring> Vector<K> {
+    pub fn zero(size: usize) -> Self {
+        V!(vec![K::default(); size])
+    }
 
     pub fn add(&mut self, v: &Vector<K>) {
         *self += v;
     }
 
+    pub fn scl(&mut self, a: K) {
+        K::slice_scale(&mut self._d, a);
+    }
+}
+
+impl<K: Ring> Vector<K> {
     pub fn sub(&mut self, vec: &Vector<K>) {
         *self -= vec;
     }
 
-    pub fn scl(&mut self, a: K) {
-        *self *= &a;
+    // u . (v x w), the signed volume spanned by the three vectors
+    pub fn triple_product(&self, v: &Vector<K>, w: &Vector<K>) -> K {
+        K::slice_mul_sum(&self._d, &cross_product(v, w)._d)
+    }
+}
+
+impl<K: Field> Vector<K> {
+    // component along v, v need not be a unit vector
+    pub fn project(&self, v: &Vector<K>) -> Vector<K> {
+        // only an exact zero is refused, `is_non_zero` would also reject
+        // short vectors whose squared norm falls under its threshold
+        let vv = v.dot(v);
+        assert!(vv != K::default(), "vector must be non-zero");
+
+        v * &(self.dot(v) / vv)
     }
 
+    // component orthogonal to v
+    pub fn reject(&self, v: &Vector<K>) -> Vector<K> {
+        self.clone() - self.project(v)
+    }
+
+    // mirror image in the hyperplane with the given normal
+    pub fn reflect(&self, normal: &Vector<K>) -> Vector<K> {
+        let p = self.project(normal);
+        self.clone() - p.clone() - p
+    }
+}
+
+impl<K: ComplexField> Vector<K> {
     pub fn norm_1(&self) -> K::AbsOutput {
         let mut sum = K::AbsOutput::default();
         for x in &self._d {
@@ -224,8 +447,8 @@ impl<K: Scalar> Vector<K> {
     pub fn norm(&self) -> K::AbsOutput {
         let mut sum = K::AbsOutput::default();
         for x in &self._d {
-            let a = x.abs().clone();
-            sum = a.mul_add(&a, &sum);
+            let a = x.abs();
+            sum = a.clone().mul_add(&a, &sum);
         }
         sum.sqrt()
     }
@@ -240,4 +463,46 @@ impl<K: Scalar> Vector<K> {
         }
         max
     }
+
+    pub fn distance(&self, v: &Vector<K>) -> K::AbsOutput {
+        (self.clone() - v.clone()).norm()
+    }
+
+    pub fn distance_1(&self, v: &Vector<K>) -> K::AbsOutput {
+        (self.clone() - v.clone()).norm_1()
+    }
+
+    pub fn distance_inf(&self, v: &Vector<K>) -> K::AbsOutput {
+        (self.clone() - v.clone()).norm_inf()
+    }
+}
+
+impl<K: ComplexField> Vector<K>
+where
+    K::AbsOutput: Cast<K>,
+{
+    pub fn normalize(&self) -> Vector<K> {
+        self.try_normalize().expect("vector must be non-zero")
+    }
+
+    pub fn try_normalize(&self) -> Option<Vector<K>> {
+        let norm: K = self.norm().cast();
+        (norm != K::default()).then(|| self * &norm.inv())
+    }
+}
+
+impl<K: ComplexField> Vector<K>
+where
+    K::AbsOutput: Float + Cast<K>,
+{
+    // angle in [0, pi] as 2 atan2(|u |v| - v |u||, |u |v| + v |u||), unlike
+    // acos of `angle_cos` it keeps full precision for nearly parallel and
+    // nearly opposite vectors
+    pub fn angle(&self, v: &Vector<K>) -> K::AbsOutput {
+        let a = self * &v.norm().cast();
+        let b = v * &self.norm().cast();
+        let y = (a.clone() - b.clone()).norm();
+        let x = (a + b).norm();
+        K::AbsOutput::from_f32(2.) * y.atan2(x)
+    }
 }
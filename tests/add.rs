@@ -5,7 +5,7 @@ fn test_vector_add() {
     let mut u = V!([2., 3.]);
     let v = V!([5., 7.]);
     u.add(&v);
-    assert!(u._d.iter().eq(&[7., 10.]));
+    assert!(u.iter().eq(&[7., 10.]));
 }
 
 #[test]
@@ -29,7 +29,7 @@ fn test_matrix_add() {
     let mut u = M!([[1., 2.], [3., 4.]]);
     let v = M!([[7., 4.], [-2., 2.]]);
     u.add(&v);
-    assert!(u._d.iter().eq(&[8., 6., 1., 6.]));
+    assert!(u.iter().eq(&[8., 6., 1., 6.]));
 }
 
 #[test]
@@ -55,7 +55,7 @@ fn test_add_complex_vector() {
     let mut u = V!([C!(1., 2.), C!(-1., -3.)]);
     let v = V!([C!(1., 3.), C!(2., 3.)]);
     u.add(&v);
-    assert!(u._d.iter().eq(&[C!(2., 5.), C!(1., 0.)]));
+    assert!(u.iter().eq(&[C!(2., 5.), C!(1., 0.)]));
 }
 
 #[test]
@@ -64,7 +64,6 @@ fn test_add_complex_matrix() {
     let v = M!([[C!(7., 0.), C!(3., 0.)], [C!(-2., 0.), C!(2., 0.)],]);
     u.add(&v);
     assert!(u
-        ._d
         .iter()
         .eq(&[C!(8., 0.), C!(5., 0.), C!(1., 0.), C!(7., 0.)]));
 }
@@ -0,0 +1,157 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+use matrix::{Dot, Matrix, Semiring, Transpose, Vector, M, V};
+
+// max-plus semiring: `+` is max and `*` is addition, so matrix products
+// compute longest paths
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Tropical(f64);
+
+impl Default for Tropical {
+    fn default() -> Self {
+        Tropical(f64::NEG_INFINITY)
+    }
+}
+
+impl Add for Tropical {
+    type Output = Tropical;
+    fn add(self, rhs: Tropical) -> Tropical {
+        Tropical(self.0.max(rhs.0))
+    }
+}
+
+impl AddAssign for Tropical {
+    fn add_assign(&mut self, rhs: Tropical) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul for Tropical {
+    type Output = Tropical;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Tropical) -> Tropical {
+        Tropical(self.0 + rhs.0)
+    }
+}
+
+impl MulAssign for Tropical {
+    fn mul_assign(&mut self, rhs: Tropical) {
+        *self = *self * rhs;
+    }
+}
+
+impl Semiring for Tropical {
+    fn one() -> Tropical {
+        Tropical(0.)
+    }
+}
+
+// boolean semiring, matrix powers give reachability
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Bool(bool);
+
+impl Add for Bool {
+    type Output = Bool;
+    fn add(self, rhs: Bool) -> Bool {
+        Bool(self.0 || rhs.0)
+    }
+}
+
+impl AddAssign for Bool {
+    fn add_assign(&mut self, rhs: Bool) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul for Bool {
+    type Output = Bool;
+    fn mul(self, rhs: Bool) -> Bool {
+        Bool(self.0 && rhs.0)
+    }
+}
+
+impl MulAssign for Bool {
+    fn mul_assign(&mut self, rhs: Bool) {
+        *self = *self * rhs;
+    }
+}
+
+impl Semiring for Bool {
+    fn one() -> Bool {
+        Bool(true)
+    }
+}
+
+#[test]
+fn test_tropical() {
+    let n = Tropical::default();
+    let t = Tropical;
+    let m = M!([[t(0.), t(3.), n], [n, t(0.), t(2.)], [t(1.), n, t(0.)]]);
+
+    let m2 = m.mul_mat(&m);
+    assert!(m2.iter().eq(&[
+        t(0.),
+        t(3.),
+        t(5.),
+        t(3.),
+        t(0.),
+        t(2.),
+        t(1.),
+        t(4.),
+        t(0.)
+    ]));
+    assert_eq!(m.trace(), Some(t(0.)));
+    assert_eq!(m.transpose()[(0, 2)], t(1.));
+
+    let v = m.mul_vec(&V!([t(0.), n, n]));
+    assert!(v.iter().eq(&[t(0.), n, t(1.)]));
+
+    let mut s = m.clone();
+    s += &Matrix::new(3, 3, vec![t(4.); 9]);
+    assert!(s.iter().all(|x| x.0 >= 4.));
+}
+
+#[test]
+fn test_boolean() {
+    let (f, t) = (Bool(false), Bool(true));
+    let m = M!([[f, t, f, f], [f, f, t, f], [f, f, f, t], [f, f, f, f]]);
+
+    let mut reach = m.clone();
+    let mut power = m.clone();
+    for _ in 0..3 {
+        power = power.mul_mat(&m);
+        reach += &power;
+    }
+    assert!(reach.iter().eq(&[
+        f, t, t, t, //
+        f, f, t, t, //
+        f, f, f, t, //
+        f, f, f, f
+    ]));
+    assert_eq!(power.iter().filter(|x| x.0).count(), 0);
+    assert_eq!(m.trace(), Some(f));
+
+    let u: Vector<Bool> = V!([t, f, t]);
+    let w: Vector<Bool> = V!([f, f, t]);
+    assert_eq!(u.dot(&w), t);
+}
+
+#[test]
+fn test_integer_ring() {
+    let a: Matrix<i64> = M!([[2, -1, 0], [1, 3, 4], [0, 5, -2]]);
+    let b: Matrix<i64> = M!([[1, 0, 2], [0, 1, 0], [3, 0, 1]]);
+
+    assert!(a.mul_mat(&b).iter().eq(&[2, -1, 4, 13, 3, 6, -6, 5, -2]));
+    assert_eq!(a.trace(), Some(3));
+    assert!(a.transpose().iter().eq(&[2, 1, 0, -1, 3, 5, 0, 4, -2]));
+    assert_eq!(a.determinant(), -54);
+    assert_eq!(b.determinant(), -5);
+
+    let mut c = a.clone();
+    c.sub(&b);
+    assert!(c.iter().eq(&[1, -1, -2, 1, 2, 4, -3, 5, -3]));
+    assert!(a.mul_mat_strassen(&b, 1).iter().eq(a.mul_mat(&b).iter()));
+
+    let v = a.mul_vec(&V!([1, 1, 1]));
+    assert!(v.iter().eq(&[1, 8, 3]));
+}
@@ -0,0 +1,182 @@
+use matrix::{
+    scalar::{Lerp, Sqrt},
+    BigInt, BigRational, Matrix, Rational, Semiring,
+};
+
+fn big(s: &str) -> BigInt {
+    s.parse().unwrap()
+}
+
+fn hilbert(n: usize) -> Matrix<BigRational> {
+    Matrix::new(
+        n,
+        n,
+        (0..n * n)
+            .map(|k| {
+                Rational::new(
+                    BigInt::from(1),
+                    BigInt::from((k / n + k % n + 1) as i64),
+                )
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn test_bigint_parse_display() {
+    for s in ["0", "-1", "4294967296", "-123456789012345678901234567890"] {
+        assert_eq!(big(s).to_string(), s);
+    }
+    assert_eq!(big("+42"), BigInt::from(42));
+    assert_eq!(big("-0"), BigInt::zero());
+    assert!("12a".parse::<BigInt>().is_err());
+    assert!("".parse::<BigInt>().is_err());
+}
+
+#[test]
+fn test_bigint_matches_i128() {
+    // small linear congruential generator so the test is deterministic
+    let mut seed = 0x2545f4914f6cdd1d_u64;
+    let mut next = || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (seed >> 1) as i64 >> (seed % 40)
+    };
+
+    for _ in 0..500 {
+        let (a, b) = (next() as i128, next() as i128);
+        let (x, y) = (BigInt::from(a), BigInt::from(b));
+
+        assert_eq!(x.clone() + y.clone(), BigInt::from(a + b));
+        assert_eq!(x.clone() - y.clone(), BigInt::from(a - b));
+        assert_eq!(x.clone() * y.clone(), BigInt::from(a * b));
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+        if b != 0 {
+            assert_eq!(x.clone() / y.clone(), BigInt::from(a / b));
+            assert_eq!(x % y, BigInt::from(a % b));
+        }
+    }
+}
+
+#[test]
+fn test_bigint_long_division() {
+    let a = big("123456789012345678901234567890123456789012345678901234567890");
+    let b = big("-987654321098765432109876543210987");
+    let (q, r) = a.div_rem(&b);
+
+    assert_eq!(q.clone() * b.clone() + r.clone(), a);
+    assert!(r.abs() < b.abs());
+    assert_eq!(q, big("-124999998860937500014238281"));
+
+    let p = BigInt::from(2).pow(200) - BigInt::from(1);
+    let (q, r) = p.div_rem(&BigInt::from(2).pow(100));
+    assert_eq!(q, BigInt::from(2).pow(100) - BigInt::from(1));
+    assert_eq!(r, BigInt::from(2).pow(100) - BigInt::from(1));
+}
+
+#[test]
+fn test_bigint_scalar() {
+    let a = BigInt::from(3).pow(80);
+    assert_eq!((a.clone() * a.clone()).sqrt(), a);
+    assert_eq!(BigInt::from(17).sqrt(), BigInt::from(4));
+    assert_eq!(BigInt::from(-7).abs(), BigInt::from(7));
+    assert_eq!(BigInt::from(2).pow(10), BigInt::from(1024));
+    assert_eq!(
+        BigInt::lerp(BigInt::from(0), BigInt::from(100), 0.25),
+        BigInt::from(25)
+    );
+    assert_eq!(BigInt::from(2).pow(70).to_f64(), 2f64.powi(70));
+    assert_eq!(BigInt::from_f64(-1e20), big("-100000000000000000000"));
+}
+
+#[test]
+fn test_bigint_vandermonde_determinant() {
+    // det V(x) = prod_{i < j} (x_j - x_i) with entries far beyond i64
+    let n = 20;
+    let mut data = vec![];
+    for i in 0..n {
+        for j in 0..n {
+            data.push(BigInt::from(i as i64 + 1).pow(j as u64));
+        }
+    }
+    let m = Matrix::new(n, n, data);
+
+    let mut expected = BigInt::from(1);
+    for i in 0..n {
+        for j in i + 1..n {
+            expected *= BigInt::from((j - i) as i64);
+        }
+    }
+    assert_eq!(m.determinant(), expected);
+}
+
+#[test]
+fn test_bigint_inverse_through_rational() {
+    let m: Matrix<BigInt> = Matrix::new(
+        2,
+        2,
+        vec![
+            BigInt::from(2),
+            BigInt::from(0),
+            BigInt::from(0),
+            BigInt::from(2),
+        ],
+    );
+    assert_eq!(m.determinant(), BigInt::from(4));
+
+    let inv = m.map(BigRational::from).inverse().unwrap();
+    let half = Rational::new(BigInt::from(1), BigInt::from(2));
+    assert!(inv.iter().eq([
+        half.clone(),
+        BigRational::default(),
+        BigRational::default(),
+        half
+    ]
+    .iter()));
+}
+
+#[test]
+fn test_big_rational_hilbert() {
+    let n = 20;
+    let h = hilbert(n);
+
+    // det H_n = c_n^4 / c_2n with c_n = prod_{i < n} i!
+    let c = |n: u64| {
+        let mut ret = BigInt::from(1);
+        let mut fact = BigInt::from(1);
+        for i in 1..n {
+            fact *= BigInt::from(i as i64);
+            ret *= fact.clone();
+        }
+        ret
+    };
+    let expected = Rational::new(BigInt::from(1), c(40) / c(20).pow(4));
+    assert_eq!(h.determinant(), expected);
+
+    let inv = h.inverse().unwrap();
+    assert!(inv.iter().all(|v| v.is_integer()));
+    assert_eq!(inv[(0, 0)], Rational::from(BigInt::from(400)));
+
+    let id = h.mul_mat(&inv);
+    for i in 0..n {
+        for j in 0..n {
+            let v = if i == j {
+                BigRational::one()
+            } else {
+                BigRational::default()
+            };
+            assert_eq!(id[(i, j)], v);
+        }
+    }
+
+    assert_eq!(h.rank(), n);
+}
+
+#[test]
+fn test_big_rational_sqrt() {
+    let q = |a: i64, b: i64| Rational::new(BigInt::from(a), BigInt::from(b));
+
+    assert_eq!(q(9, 49).sqrt(), q(3, 7));
+    let r = q(2, 1).sqrt();
+    assert!((r.to_f64() - 2f64.sqrt()).abs() < 1e-15);
+    assert!(r.clone() * r < q(2, 1));
+}
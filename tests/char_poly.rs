@@ -0,0 +1,110 @@
+use matrix::{approx_eq, Matrix, Polynomial, Rational64, Semiring, Zp, M};
+
+type Q = Rational64;
+
+fn q(coeffs: &[i64]) -> Polynomial<Q> {
+    Polynomial::new(coeffs.iter().map(|&c| Q::from(c)).collect())
+}
+
+#[test]
+fn test_char_poly() {
+    let a = M!([[2, 1, 0], [1, 3, 1], [0, 1, 4]]).map(Q::from);
+    let p = a.char_poly();
+    assert_eq!(p, q(&[-18, 24, -9, 1]));
+    assert_eq!(p.eval(Q::default()), -a.determinant());
+
+    let i: Matrix<i64> = M!([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+    assert_eq!(i.char_poly().coeffs(), &[3, -12, -16, 1]);
+
+    let f = M!([[2., 1., 0.], [1., 3., 1.], [0., 1., 4.]]);
+    assert!(f
+        .char_poly()
+        .coeffs()
+        .iter()
+        .zip([-18., 24., -9., 1.])
+        .all(|(a, b)| approx_eq!(*a, b)));
+
+    assert_eq!(Matrix::<Q>::new(0, 0, vec![]).char_poly(), q(&[1]));
+    assert_eq!(M!([[Q::from(5)]]).char_poly(), q(&[-5, 1]));
+}
+
+#[test]
+fn test_cayley_hamilton() {
+    let a: Matrix<i64> =
+        M!([[1, -2, 0, 3], [4, 0, 1, -1], [2, 5, -3, 0], [0, 1, 1, 2]]);
+    let p = a.char_poly();
+    assert!(p.eval_mat(&a).iter().all(|v| *v == 0));
+
+    let f = a.map(|v| v as f64);
+    assert!(f.char_poly().eval_mat(&f).iter().all(|v| v.abs() < 1e-9));
+
+    let one: Matrix<i64> = M!([[1, 1], [0, 1]]);
+    let x = Polynomial::new(vec![1, 2, 1]).eval_mat(&one);
+    assert!(x.iter().eq(&[4, 4, 0, 4]));
+}
+
+#[test]
+fn test_adjugate() {
+    let a: Matrix<i64> =
+        M!([[1, -2, 0, 3], [4, 0, 1, -1], [2, 5, -3, 0], [0, 1, 1, 2]]);
+    let adj = a.adjugate();
+    assert!(adj.iter().eq(a.checked_adjugate().unwrap().iter()));
+    let det = a.determinant();
+    let prod = a.mul_mat(&adj);
+    assert!(prod
+        .iter()
+        .eq(Matrix::identity(4).map(|v: i64| v * det).iter()));
+
+    // singular matrices still have an adjugate
+    let s: Matrix<i64> = M!([[1, 2], [2, 4]]);
+    assert!(s.adjugate().iter().eq(&[4, -2, -2, 1]));
+    let s: Matrix<i64> = M!([[7]]);
+    assert!(s.adjugate().iter().eq(&[1]));
+
+    let f = M!([[2., 0., 1.], [1., 3., 0.], [0., 1., 4.]]);
+    let inv = f.inverse().unwrap();
+    let det = f.determinant();
+    assert!(f
+        .adjugate()
+        .iter()
+        .zip(inv.iter())
+        .all(|(a, b)| approx_eq!(*a, b * det)));
+}
+
+#[test]
+fn test_min_poly() {
+    let d = M!([[2, 0, 0], [0, 2, 0], [0, 0, 3]]).map(Q::from);
+    assert_eq!(d.min_poly(), q(&[6, -5, 1]));
+    assert_eq!(d.char_poly(), q(&[-12, 16, -7, 1]));
+
+    // a Jordan block needs the full characteristic polynomial
+    let j = M!([[2, 1, 0], [0, 2, 1], [0, 0, 2]]).map(Q::from);
+    assert_eq!(j.min_poly(), j.char_poly());
+
+    let s = Matrix::identity(3).map(|v: Q| v * Q::from(4));
+    assert_eq!(s.min_poly(), q(&[-4, 1]));
+    let z = Matrix::new(2, 2, vec![Q::default(); 4]);
+    assert_eq!(z.min_poly(), q(&[0, 1]));
+
+    let a = M!([[1, -2, 0, 3], [4, 0, 1, -1], [2, 5, -3, 0], [0, 1, 1, 2]])
+        .map(Q::from);
+    let m = a.min_poly();
+    assert_eq!(m.leading(), Q::one());
+    assert!(m.eval_mat(&a).iter().all(|v| *v == Q::default()));
+    assert_eq!(a.char_poly() % m, Polynomial::default());
+}
+
+#[test]
+fn test_char_poly_small_characteristic() {
+    // no division by the matrix size, which vanishes in Z/2 for n >= 2
+    let i = Matrix::<Zp<2>>::identity(2);
+    assert_eq!(i.char_poly().coeffs(), &[1u64, 0, 1].map(Zp::<2>::from));
+    assert!(i.adjugate().iter().eq(i.iter()));
+
+    let a = M!([[1u64, 1, 0], [0, 1, 1], [1, 0, 1]]).map(Zp::<2>::from);
+    let p = a.char_poly();
+    assert!(p.eval_mat(&a).iter().all(|v| !v.is_non_zero()));
+    assert_eq!(p.eval(Zp::default()), a.determinant());
+    let det = Matrix::identity(3).map(|v: Zp<2>| v * a.determinant());
+    assert!(a.mul_mat(&a.adjugate()).iter().eq(det.iter()));
+}
@@ -0,0 +1,90 @@
+use matrix::{
+    approx_eq,
+    scalar::{Cast, Lerp, MulAdd, Sqrt},
+    Complex, Complex32, Complex64, ComplexField, Dot, Field, Matrix, Semiring,
+    Vector, C,
+};
+
+#[test]
+fn test_complex32_arithmetic() {
+    let a = C!(1., 2.; f32);
+    let b = C!(3., -1.; f32);
+
+    assert_eq!(a + b, C!(4., 1.; f32));
+    assert_eq!(a - b, C!(-2., 3.; f32));
+    assert_eq!(a * b, C!(5., 5.; f32));
+    assert_eq!((a * b) / b, a);
+    assert_eq!(-a, C!(-1., -2.; f32));
+    assert_eq!(a * 2_f32, C!(2., 4.; f32));
+}
+
+#[test]
+fn test_complex32_scalar() {
+    let a = C!(3., 4.; f32);
+
+    assert!(approx_eq!(a.abs(), 5_f32));
+    assert_eq!(a.conj(), C!(3., -4.; f32));
+    assert_eq!(a * a.inv(), Complex32::one());
+    assert_eq!(a.real(), 3_f32);
+    assert!(a.is_non_zero());
+    assert!(!Complex32::default().is_non_zero());
+}
+
+#[test]
+fn test_complex32_mul_add() {
+    let a = C!(1., 2.; f32);
+    let b = C!(3., -1.; f32);
+    let c = C!(0.5, 0.5; f32);
+
+    assert_eq!(a.mul_add(&b, &c), a * b + c);
+    assert_eq!(a.mul_add(&2_f32, &c), a * 2_f32 + c);
+}
+
+#[test]
+fn test_complex32_sqrt() {
+    let a = C!(3., 4.; f32);
+    assert_eq!(a.sqrt(), C!(2., 1.; f32));
+}
+
+#[test]
+fn test_complex32_lerp() {
+    let a = C!(0., 0.; f32);
+    let b = C!(2., 4.; f32);
+
+    assert_eq!(Complex::lerp(a, b, 0.), a);
+    assert_eq!(Complex::lerp(a, b, 1.), b);
+    assert_eq!(Complex::lerp(a, b, 0.5), C!(1., 2.; f32));
+}
+
+#[test]
+fn test_complex32_vector_and_matrix() {
+    let u: Vector<Complex32> = Vector::from([C!(1., 1.; f32), C!(0., 2.; f32)]);
+    let v: Vector<Complex32> =
+        Vector::from([C!(2., 0.; f32), C!(1., -1.; f32)]);
+    assert_eq!(u.dot(&v), C!(0., 4.; f32));
+
+    let m: Matrix<Complex32> = Matrix::new(
+        2,
+        2,
+        vec![
+            C!(1., 0.; f32),
+            C!(0., 1.; f32),
+            C!(0., -1.; f32),
+            C!(1., 0.; f32),
+        ],
+    );
+    assert_eq!(m.trace(), Some(C!(2., 0.; f32)));
+}
+
+#[test]
+fn test_complex_cast() {
+    let a: Complex64 = C!(1.5, -2.5);
+    let b: Complex32 = a.cast();
+    assert_eq!(b, C!(1.5, -2.5; f32));
+
+    let c: Complex64 = b.cast();
+    assert_eq!(c, a);
+
+    let d: Complex32 = 2_f64.cast();
+    assert_eq!(d, C!(2., 0.; f32));
+}
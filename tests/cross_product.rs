@@ -5,17 +5,17 @@ fn test_cross_product() {
     let u = V!([0., 0., 1.]);
     let v = V!([1., 0., 0.]);
     let a = cross_product(&u, &v);
-    assert!(a._d.iter().eq(&[0., 1., 0.]));
+    assert!(a.iter().eq(&[0., 1., 0.]));
 
     let u = V!([1., 2., 3.]);
     let v = V!([4., 5., 6.]);
     let b = cross_product(&u, &v);
-    assert!(b._d.iter().eq(&[-3., 6., -3.]));
+    assert!(b.iter().eq(&[-3., 6., -3.]));
 
     let u = V!([4., 2., -3.]);
     let v = V!([-2., -5., 16.]);
     let c = cross_product(&u, &v);
-    assert!(c._d.iter().eq(&[17., -58., -16.]));
+    assert!(c.iter().eq(&[17., -58., -16.]));
 }
 
 #[test]
@@ -32,5 +32,5 @@ fn test_cross_product_complex() {
     let u = V!([C!(1., 2.), C!(3., 4.), C!(5., 6.)]);
     let v = V!([C!(7., 8.), C!(9., 10.), C!(11., 12.)]);
     let a = cross_product(&u, &v);
-    assert!(a._d.iter().eq(&[C!(0., -24.), C!(0., 48.), C!(0., -24.)]));
+    assert!(a.iter().eq(&[C!(0., -24.), C!(0., 48.), C!(0., -24.)]));
 }
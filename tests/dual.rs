@@ -0,0 +1,133 @@
+use std::{cmp::Ordering, f64::consts::PI};
+
+use matrix::{
+    angle_cos, approx_eq, derivative, gradient, jacobian, projection,
+    ComplexField, Dual, Field, Matrix, Semiring, Vector, M, V,
+};
+
+fn d(x: f64, dx: f64) -> Dual {
+    Dual::new(x, dx)
+}
+
+#[test]
+fn test_dual_derivatives() {
+    let x: f64 = 0.7;
+    assert!(approx_eq!(derivative(|x| x.sin(), x), x.cos()));
+    assert!(approx_eq!(derivative(|x| x.cos(), x), -x.sin()));
+    assert!(approx_eq!(
+        derivative(|x| x.tan(), x),
+        1. / (x.cos() * x.cos())
+    ));
+    assert!(approx_eq!(derivative(|x| x.exp().ln(), x), 1.));
+    assert!(approx_eq!(
+        derivative(|x| x.asin(), x),
+        1. / (1. - x * x).sqrt()
+    ));
+    assert!(approx_eq!(derivative(|x| x.atan(), x), 1. / (1. + x * x)));
+    assert!(approx_eq!(derivative(|x| x.sinh(), x), x.cosh()));
+    assert!(approx_eq!(derivative(|x| x * x * x, x), 3. * x * x));
+    assert!(approx_eq!(derivative(|x| x.inv(), x), -1. / (x * x)));
+    assert!(approx_eq!(
+        derivative(|x| (x * x + Dual::from(1.)) / x, x),
+        1. - 1. / (x * x)
+    ));
+
+    // x^x, the exponent carries a derivative too
+    assert!(approx_eq!(
+        derivative(|x| x.powf(x), x),
+        x.powf(x) * (x.ln() + 1.)
+    ));
+    assert!(approx_eq!(derivative(|x| x.powf(Dual::from(3.)), 0.), 0.));
+    assert!(approx_eq!(
+        derivative(|x| x.hypot(Dual::from(2.)), 1.5),
+        1.5 / 2.5
+    ));
+}
+
+#[test]
+fn test_dual_ordering() {
+    assert!(d(1., 5.) < d(2., -5.));
+    let a = d(-3., 2.).abs();
+    assert_eq!((a.x, a.dx), (3., -2.));
+    assert!(!d(0., 1.).is_non_zero());
+
+    // consistent with the ordering, the derivative does not take part
+    assert_eq!(d(1., 1.), d(1., 2.));
+    assert_eq!(d(1., 1.).partial_cmp(&d(1., 2.)), Some(Ordering::Equal));
+    assert!(d(1., 1.) != d(2., 1.));
+}
+
+#[test]
+fn test_gradient() {
+    let x = V!([3., 4., 12.]);
+
+    let g = gradient(|v| v.norm(), &x);
+    assert!(g
+        .iter()
+        .zip(&[3. / 13., 4. / 13., 12. / 13.])
+        .all(|(a, b)| approx_eq!(*a, *b)));
+
+    // finite differences on angle_cos agree with the exact gradient
+    let y = V!([1., -2., 0.5]);
+    let f = |v: &Vector<f64>| angle_cos(v, &y);
+    let g = gradient(|v| angle_cos(v, &y.map(Dual::from)), &x);
+    for j in 0..3 {
+        let mut h = x.clone();
+        h[j] += 1e-6;
+        assert!(approx_eq!(g[j], (f(&h) - f(&x)) / 1e-6, 1e-5));
+    }
+}
+
+#[test]
+fn test_jacobian() {
+    let (r, t) = (2., 0.3);
+    let polar = |v: &Vector<Dual>| V!([v[0] * v[1].cos(), v[0] * v[1].sin()]);
+    let j = jacobian(polar, &V!([r, t]));
+
+    assert_eq!(j.shape(), (2, 2));
+    let expected = M!([[t.cos(), -r * t.sin()], [t.sin(), r * t.cos()]]);
+    assert!(j
+        .iter()
+        .zip(expected.iter())
+        .all(|(a, b)| approx_eq!(*a, *b)));
+
+    let j = jacobian(|v| V!([v[0] + v[1] + v[2]]), &V!([1., 2., 3.]));
+    assert_eq!(j.shape(), (1, 3));
+    assert!(j.iter().all(|x| approx_eq!(*x, 1.)));
+}
+
+#[test]
+fn test_determinant_derivative() {
+    // d/dt det(A + tB) = det(A) tr(A⁻¹ B)
+    let a: Matrix<f64> = M!([[2., 1., 0.], [1., 3., 1.], [0., 1., 4.]]);
+    let b: Matrix<f64> = M!([[1., 0., 2.], [0., -1., 0.], [1., 1., 1.]]);
+    let m = a.zip_map(&b, Dual::new);
+
+    let expected =
+        a.determinant() * a.inverse().unwrap().mul_mat(&b).trace().unwrap();
+    assert!(approx_eq!(m.determinant().dx, expected));
+    assert!(approx_eq!(m.determinant().x, a.determinant()));
+}
+
+#[test]
+fn test_projection_derivative() {
+    let (fov, ratio, near, far) = (60., 1.5, 2., 50.);
+
+    // with respect to the near plane
+    let p = projection(d(fov, 0.), d(ratio, 0.), d(near, 1.), d(far, 0.));
+    assert!(approx_eq!(p[(0, 0)].dx, 0.));
+    assert!(approx_eq!(
+        p[(2, 2)].dx,
+        -far / ((near - far) * (near - far))
+    ));
+
+    // with respect to the field of view
+    let p = projection(d(fov, 1.), d(ratio, 0.), d(near, 0.), d(far, 0.));
+    let a = fov / 2. * PI / 180.;
+    assert!(approx_eq!(p[(1, 1)].x, 1. / a.tan(), 1e-5));
+    assert!(approx_eq!(
+        p[(1, 1)].dx,
+        -PI / 360. / (a.sin() * a.sin()),
+        1e-5
+    ));
+}
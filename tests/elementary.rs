@@ -0,0 +1,140 @@
+use std::f64::consts::{E, FRAC_PI_2, PI};
+
+use matrix::{
+    approx_eq,
+    scalar::{Float, Sqrt},
+    Complex, Complex32, ComplexField, Semiring, C,
+};
+
+fn check_real_identities<T: Float>(x: T) {
+    let tol = T::from_f32(1e-4);
+    let one = T::one();
+
+    assert!(approx_eq!(x.sin() * x.sin() + x.cos() * x.cos(), one, tol));
+    assert!(approx_eq!(
+        x.cosh() * x.cosh() - x.sinh() * x.sinh(),
+        one,
+        tol
+    ));
+    assert!(approx_eq!(x.tan(), x.sin() / x.cos(), tol));
+    assert!(approx_eq!(x.exp().ln(), x, tol));
+    assert!(approx_eq!(x.sin().asin(), x, tol));
+    assert!(approx_eq!(x.cos().acos(), x, tol));
+    assert!(approx_eq!(x.tan().atan(), x, tol));
+    assert!(approx_eq!(x.exp().powf(x), (x * x).exp(), tol));
+    assert!(approx_eq!(x.exp().powc(x), (x * x).exp(), tol));
+    assert!(approx_eq!(x.sin().hypot(x.cos()), one, tol));
+    assert!(approx_eq!(x.sin().atan2(x.cos()), x, tol));
+}
+
+#[test]
+fn test_elementary_f64() {
+    assert_eq!(0_f64.cos(), 1.);
+    assert!(approx_eq!(PI.cos(), -1.));
+    assert!(approx_eq!(1_f64.exp(), E));
+    assert!(approx_eq!(E.ln(), 1.));
+    assert!(approx_eq!(2_f64.powf(10.), 1024.));
+    assert!(approx_eq!(1_f64.asin(), FRAC_PI_2));
+    assert!(approx_eq!((-1_f64).acos(), PI));
+    assert!(approx_eq!(1_f64.atan(), PI / 4.));
+    assert_eq!(3_f64.hypot(4.), 5.);
+
+    for x in [0.1, 0.5, 1., 1.5] {
+        check_real_identities(x);
+    }
+}
+
+#[test]
+fn test_elementary_f32() {
+    assert_eq!(0_f32.cos(), 1.);
+    assert!(approx_eq!(2_f32.powf(3.), 8.));
+    assert_eq!(3_f32.hypot(4.), 5.);
+
+    for x in [0.1_f32, 0.5, 1., 1.5] {
+        check_real_identities(x);
+    }
+}
+
+fn check_complex_identities<T: Float>(z: Complex<T>) {
+    let tol = T::from_f32(1e-4);
+    let one = Complex::<T>::one();
+
+    assert!(approx_eq!(z.sin() * z.sin() + z.cos() * z.cos(), one, tol));
+    assert!(approx_eq!(
+        z.cosh() * z.cosh() - z.sinh() * z.sinh(),
+        one,
+        tol
+    ));
+    assert!(approx_eq!(z.tan(), z.sin() / z.cos(), tol));
+    assert!(approx_eq!(z.exp().ln(), z, tol));
+    assert!(approx_eq!(z.asin().sin(), z, tol));
+    assert!(approx_eq!(z.acos().cos(), z, tol));
+    assert!(approx_eq!(z.atan().tan(), z, tol));
+    assert!(approx_eq!(z.sqrt() * z.sqrt(), z, tol));
+    assert!(approx_eq!(z.powf(T::one() + T::one()), z * z, tol));
+    assert!(approx_eq!(z.powc(one + one), z * z, tol));
+
+    let (r, theta) = z.to_polar();
+    assert!(approx_eq!(Complex::from_polar(r, theta), z, tol));
+    assert!(approx_eq!(
+        z.hypot(z),
+        r * (T::one() + T::one()).sqrt(),
+        tol
+    ));
+}
+
+#[test]
+fn test_elementary_complex() {
+    let i = Complex::<f64>::i();
+
+    assert!(approx_eq!(C!(0., PI).exp(), C!(-1., 0.)));
+    assert!(approx_eq!(C!(-1., 0.).ln(), C!(0., PI)));
+    assert!(approx_eq!(i.powc(i), C!((-FRAC_PI_2).exp(), 0.)));
+
+    // 0^0 = 1 and 0^n = 0 for n > 0 like f64::powf, 0^-1 blows up
+    let zero = Complex::<f64>::default();
+    assert_eq!(zero.powf(0.), Complex::one());
+    assert_eq!(zero.powc(zero), Complex::one());
+    assert_eq!(zero.powf(2.), zero);
+    assert_eq!(zero.powc(C!(2., 1.)), zero);
+    assert!(zero.powf(-1.).x.is_infinite());
+    assert!(!zero.powc(C!(-1., 0.)).x.is_finite());
+
+    // only exact zeros take the shortcuts
+    let tiny = C!(1e-11, 0.);
+    assert!(approx_eq!(tiny.powf(1.).x, 1e-11, 1e-24));
+    assert!(approx_eq!(tiny.powc(Complex::one()).x, 1e-11, 1e-24));
+    let p = C!(4., 0.).powf(1e-12);
+    assert!(p != Complex::one() && approx_eq!(p.x, 4_f64.powf(1e-12), 1e-15));
+
+    assert!(approx_eq!(i.arg(), FRAC_PI_2));
+    assert!(approx_eq!(C!(-2., 0.).arg(), PI));
+    assert_eq!(C!(3., 4.).to_polar().0, 5.);
+    assert!(approx_eq!(C!(0., 1.).cos(), C!(1_f64.cosh(), 0.)));
+    assert!(approx_eq!(
+        C!(1., 1.).cos(),
+        C!(0.83373002513, -0.98889770576)
+    ));
+
+    for z in [C!(0.5, 0.25), C!(-0.3, 0.8), C!(1., -1.), C!(0.2, 0.)] {
+        check_complex_identities(z);
+    }
+}
+
+#[test]
+fn test_elementary_complex32() {
+    let z: Complex32 = C!(3., 4.; f32);
+    assert_eq!(z.to_polar().0, 5.);
+
+    for z in [C!(0.5, 0.25; f32), C!(-0.3, 0.8; f32), C!(1., -1.; f32)] {
+        check_complex_identities(z);
+    }
+}
+
+#[test]
+fn test_complex_sqrt_real_input() {
+    assert_eq!(C!(4., 0.).sqrt(), C!(2., 0.));
+    assert_eq!(C!(-4., 0.).sqrt(), C!(0., 2.));
+    assert_eq!(C!(0., 0.).sqrt(), C!(0., 0.));
+    assert_eq!(C!(3., -4.).sqrt(), C!(2., -1.));
+}
@@ -0,0 +1,80 @@
+use matrix::{lerp, Complex, Expr, Layout, Matrix, C, M};
+
+#[test]
+fn test_expr_matches_eager() {
+    let a = M!([[1.5, -2.], [3.25, 4.]]);
+    let b = M!([[0.1, 0.2], [0.3, 0.4]]);
+    let c = M!([[7., -8.], [9., 1e-3]]);
+
+    let lazy = (a.lazy() + &b - c.lazy() * 2.).eval();
+    let eager = a.clone() + b.clone() - c.clone() * 2.;
+    assert!(lazy.iter().eq(eager.iter()));
+    assert_eq!(lazy.shape(), (2, 2));
+
+    let lazy = (-(a.lazy() - &b) * 0.5 + &c).eval();
+    let eager = (b.clone() - a.clone()) * 0.5 + c.clone();
+    assert!(lazy.iter().eq(eager.iter()));
+}
+
+#[test]
+fn test_expr_lerp() {
+    let u = M!([[2., 1.], [3., 4.]]);
+    let v = M!([[20., 10.], [30., 40.]]);
+
+    for t in [0., 0.3, 0.5, 1.] {
+        let lazy = u.lazy().lerp(&v, t).eval();
+        let eager = lerp(u.clone(), v.clone(), t);
+        assert!(lazy.iter().eq(eager.iter()));
+    }
+
+    // both paths keep the single rounding of the fused eager lerp
+    let p = 0.3f32 as f64;
+    let fused = u
+        .iter()
+        .zip(v.iter())
+        .map(|(a, b)| f64::mul_add(b - a, p, *a));
+    assert!(lerp(u.clone(), v.clone(), 0.3).iter().copied().eq(fused));
+
+    let lazy = (u.lazy().lerp(&v, 0.3) * 2. - &u).eval();
+    let eager = lerp(u.clone(), v.clone(), 0.3) * 2. - u.clone();
+    assert!(lazy.iter().eq(eager.iter()));
+}
+
+#[test]
+fn test_expr_complex() {
+    let a = M!([[C!(1., 2.), C!(3., 4.)], [C!(5., 6.), C!(7., 8.)]]);
+    let b = M!([[C!(0., 1.), C!(1., 0.)], [C!(-1., 0.), C!(0., -1.)]]);
+
+    let lazy = (a.lazy() - b.lazy() * C!(0., 2.) + &a).eval();
+    let eager = a.clone() - b.clone() * C!(0., 2.) + a.clone();
+    assert!(lazy.iter().eq(eager.iter()));
+
+    let lazy = a.lazy().lerp(&b, 0.3).eval();
+    let eager = lerp(a.clone(), b.clone(), 0.3);
+    assert!(lazy.iter().eq(eager.iter()));
+}
+
+#[test]
+fn test_expr_assign() {
+    let a = M!([[1., 2., 3.], [4., 5., 6.]]);
+    let b = a.to_layout(Layout::ColMajor);
+
+    let mut dst = Matrix::from([[0.; 3]; 2]);
+    dst.assign(a.lazy() + &b);
+    assert!(dst.iter().eq(&[2., 4., 6., 8., 10., 12.]));
+
+    let mut dst = dst.into_layout(Layout::ColMajor);
+    dst.assign(b.lazy() * 3.);
+    assert!(dst
+        .to_layout(Layout::RowMajor)
+        .iter()
+        .eq(&[3., 6., 9., 12., 15., 18.]));
+}
+
+#[test]
+#[should_panic(expected = "matrices must be the same size")]
+fn test_expr_shape_mismatch() {
+    let a = M!([[1., 2.], [3., 4.]]);
+    let b = M!([[1., 2., 3.]]);
+    let _ = a.lazy() + &b;
+}
@@ -0,0 +1,161 @@
+use matrix::{
+    approx_eq, BitMatrix, Field, Gf2, Matrix, Semiring, Transpose, Zp, M,
+};
+
+type F7 = Zp<7>;
+type M61 = Zp<{ (1 << 61) - 1 }>;
+
+fn f7<const R: usize, const C: usize>(v: [[i64; C]; R]) -> Matrix<F7> {
+    M!(v).map(F7::new)
+}
+
+// deterministic pseudo-random GF(2) matrix
+fn random_gf2(rows: usize, cols: usize, seed: u64) -> Matrix<Gf2> {
+    let mut state = seed;
+    let vec = (0..rows * cols)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            Gf2(state >> 63 == 1)
+        })
+        .collect();
+    Matrix::new(rows, cols, vec)
+}
+
+#[test]
+fn test_zp_arithmetic() {
+    let (a, b) = (F7::new(3), F7::new(5));
+    assert_eq!(a + b, F7::new(1));
+    assert_eq!(a - b, F7::new(5));
+    assert_eq!(a * b, F7::new(1));
+    assert_eq!(a / b, F7::new(2));
+    assert_eq!(-a, F7::new(4));
+    assert_eq!(F7::new(-1).value(), 6);
+    assert_eq!(F7::from(100u64).value(), 2);
+    assert_eq!(a.pow(6), F7::one());
+    assert!(!F7::new(14).is_non_zero());
+
+    for x in 1..7 {
+        assert_eq!(F7::new(x) * F7::new(x).inv(), F7::one());
+    }
+
+    let (x, y) = (M61::new(1 << 60), M61::new(12345678901234567));
+    let expected = ((1u128 << 60) * 12345678901234567 % ((1 << 61) - 1)) as u64;
+    assert_eq!((x * y).value(), expected);
+    assert_eq!(x * x.inv(), M61::one());
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_zp_division_by_zero() {
+    let _ = F7::one() / F7::default();
+}
+
+#[test]
+fn test_zp_matrix() {
+    let a = f7([[2, 3, 1], [4, 1, 5], [6, 2, 4]]);
+    assert_eq!(a.determinant(), F7::new(32));
+    assert_eq!(a.rank(), 3);
+
+    let inv = a.inverse().unwrap();
+    assert!(a
+        .mul_mat(&inv)
+        .iter()
+        .eq(f7([[1, 0, 0], [0, 1, 0], [0, 0, 1]]).iter()));
+
+    // det 42 is zero modulo 7 but not over the reals
+    let s = f7([[2, 3, 1], [4, 1, 5], [6, 2, 3]]);
+    assert_eq!(s.determinant(), F7::default());
+    assert_eq!(s.rank(), 2);
+    assert_eq!(M!([[2., 3., 1.], [4., 1., 5.], [6., 2., 3.]]).rank(), 3);
+}
+
+#[test]
+fn test_null_space() {
+    let s = f7([[2, 3, 1], [4, 1, 5], [6, 2, 3]]);
+    let n = s.null_space();
+    assert_eq!(n.shape(), (1, 3));
+    assert!(s.mul_mat(&n.transpose()).iter().all(|x| !x.is_non_zero()));
+
+    let m = M!([[1., 2., 3., 4.], [2., 4., 6., 8.], [1., 0., 1., 0.]]);
+    let n = m.null_space();
+    assert_eq!(n.shape(), (2, 4));
+    assert!(m.mul_mat(&n.transpose()).iter().all(|x| approx_eq!(*x, 0.)));
+    assert_eq!(n.rank(), 2);
+
+    let full = M!([[1., 0.], [0., 1.]]);
+    assert_eq!(full.null_space().shape(), (0, 2));
+}
+
+#[test]
+fn test_gf2_matrix() {
+    let (o, l) = (Gf2(false), Gf2(true));
+    assert_eq!(l + l, o);
+    assert_eq!(l * o, o);
+    assert_eq!(-l, l);
+
+    // parity check matrix of the Hamming(7, 4) code, its null space is the
+    // code itself
+    let h = M!([
+        [o, o, o, l, l, l, l],
+        [o, l, l, o, o, l, l],
+        [l, o, l, o, l, o, l]
+    ]);
+    let code = h.null_space();
+    assert_eq!(code.shape(), (4, 7));
+    assert!(h.mul_mat(&code.transpose()).iter().all(|x| *x == o));
+    assert_eq!(BitMatrix::from(&h).null_space().rank(), 4);
+}
+
+#[test]
+fn test_bit_matrix() {
+    for (n, seed) in [(5, 1), (64, 2), (70, 3), (130, 4)] {
+        let m = random_gf2(n, n, seed);
+        let b = BitMatrix::from(&m);
+        assert_eq!(
+            b.to_matrix().iter().collect::<Vec<_>>(),
+            m.iter().collect::<Vec<_>>()
+        );
+
+        assert_eq!(b.rank(), m.rank());
+        assert_eq!(b.determinant(), m.determinant());
+        assert!(b
+            .row_echelon()
+            .to_matrix()
+            .iter()
+            .eq(m.row_echelon().iter()));
+
+        match b.inverse() {
+            Ok(inv) => {
+                assert_eq!(b.mul_mat(&inv), BitMatrix::identity(n));
+                assert_eq!(inv.mul_mat(&b), BitMatrix::identity(n));
+                assert!(inv.to_matrix().iter().eq(m.inverse().unwrap().iter()));
+            }
+            Err(_) => assert_eq!(b.determinant(), Gf2(false)),
+        }
+
+        let ns = b.null_space();
+        assert_eq!(ns.rows + b.rank(), n);
+        assert_eq!(b.mul_mat(&ns.transpose()), BitMatrix::new(n, ns.rows));
+    }
+
+    let wide = BitMatrix::from(&random_gf2(40, 150, 5));
+    let ns = wide.null_space();
+    assert_eq!(ns.shape(), (150 - wide.rank(), 150));
+    assert_eq!(wide.mul_mat(&ns.transpose()), BitMatrix::new(40, ns.rows));
+    assert_eq!(wide.transpose().transpose(), wide);
+}
+
+#[test]
+fn test_bit_matrix_singular() {
+    let mut b = BitMatrix::new(3, 3);
+    b.set(0, 0, true);
+    b.set(1, 1, true);
+    b.set(2, 0, true);
+    b.set(2, 1, true);
+    assert!(b.get(2, 1));
+    assert_eq!(b.rank(), 2);
+    assert_eq!(b.determinant(), Gf2(false));
+    assert_eq!(b.inverse(), Err("matrix is singular"));
+}
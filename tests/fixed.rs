@@ -0,0 +1,155 @@
+use std::f64::consts::PI;
+
+use matrix::{
+    projection,
+    scalar::{Float, Sqrt},
+    vec2::rotate2,
+    vec3::rotate3z,
+    ComplexField, Field, Fixed, Matrix, Semiring, Vector, M, Q16_16, Q1_31, V,
+};
+
+type Wrapping = Fixed<16, true>;
+
+fn q(v: f64) -> Q16_16 {
+    Q16_16::from_f32(v as f32)
+}
+
+// distance in units of the last place
+fn ulps<const F: u32>(x: Fixed<F>, expected: f64) -> f64 {
+    (x.to_f64() - expected).abs() * (1u64 << F) as f64
+}
+
+#[test]
+fn test_fixed_arithmetic() {
+    let (a, b) = (q(1.5), q(2.25));
+    assert_eq!(a + b, q(3.75));
+    assert_eq!(a - b, q(-0.75));
+    assert_eq!(a * b, q(3.375));
+    assert_eq!(b / a, q(1.5));
+    assert_eq!(-a, q(-1.5));
+    assert_eq!(Q16_16::from(3).to_bits(), 3 << 16);
+    assert_eq!(Q16_16::one().to_bits(), 1 << 16);
+    assert_eq!(q(1.) / q(3.), Q16_16::from_bits(21845));
+    assert_eq!(q(2.) / q(3.), Q16_16::from_bits(43691));
+    assert!(q(-1.) < q(0.5));
+
+    // Q1.31 holds [-1, 1)
+    let h = Q1_31::from_f32(0.5);
+    assert_eq!(h * h, Q1_31::from_f32(0.25));
+    assert_eq!(Q1_31::one(), Q1_31::MAX);
+    assert_eq!(Q1_31::from_f32(-1.), Q1_31::MIN);
+    assert_eq!(Q1_31::from_f32(1.), Q1_31::MAX);
+}
+
+#[test]
+fn test_fixed_overflow() {
+    let big = q(30000.);
+    assert_eq!(big + big, Q16_16::MAX);
+    assert_eq!(-big - big, Q16_16::MIN);
+    assert_eq!(big * big, Q16_16::MAX);
+    assert_eq!(-Q16_16::MIN, Q16_16::MAX);
+    assert_eq!(q(1.) / Q16_16::default(), Q16_16::MAX);
+    assert_eq!(q(-1.) / Q16_16::default(), Q16_16::MIN);
+
+    let big = Wrapping::from_f32(30000.);
+    assert_eq!((big + big).to_bits(), 60000i32.wrapping_shl(16));
+    assert_eq!(Wrapping::MAX + Wrapping::from_bits(1), Wrapping::MIN);
+    assert_eq!(-Wrapping::MIN, Wrapping::MIN);
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_wrapping_division_by_zero() {
+    let _ = Wrapping::one() / Wrapping::default();
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_wrapping_negative_power_of_zero() {
+    let _ = Wrapping::default().powf(Wrapping::from_f32(-1.));
+}
+
+#[test]
+fn test_fixed_functions() {
+    for i in -400..=400 {
+        let x = i as f64 / 40.;
+        let v = q(x);
+        let x = v.to_f64();
+
+        assert!(ulps(v.sin(), x.sin()) <= 1., "sin {}", x);
+        assert!(ulps(v.cos(), x.cos()) <= 1., "cos {}", x);
+        assert!(ulps(v.atan(), x.atan()) <= 1., "atan {}", x);
+        assert!(ulps(v.atan2(q(0.7)), x.atan2(0.7)) <= 1., "atan2 {}", x);
+        if x.cos().abs() > 0.1 {
+            assert!(ulps(v.tan(), x.tan()) <= 2., "tan {}", x);
+        }
+        if x.abs() <= 1. {
+            assert!(ulps(v.asin(), x.asin()) <= 2., "asin {}", x);
+            assert!(ulps(v.acos(), x.acos()) <= 2., "acos {}", x);
+        }
+        if x.abs() < 6. {
+            assert!(ulps(v.exp(), x.exp()) <= 1. + x.exp() / 1e4, "exp {}", x);
+            assert!(ulps(v.sinh(), x.sinh()) <= 1. + x.cosh() / 1e4);
+        }
+        if x > 0. {
+            assert!(ulps(v.ln(), x.ln()) <= 1., "ln {}", x);
+            assert!(ulps(v.sqrt(), x.sqrt()) <= 1., "sqrt {}", x);
+            assert!(ulps(v.powf(q(1.5)), x.powf(1.5)) <= 2. + x.powf(1.5));
+        }
+    }
+
+    assert_eq!(q(-2.).sqrt(), Q16_16::default());
+    assert_eq!(q(-2.).powf(q(3.)), q(-8.));
+    assert_eq!(q(0.).powf(q(0.)), q(1.));
+    assert_eq!(q(0.).powf(q(2.)), q(0.));
+    assert_eq!(q(0.).powf(q(-1.)), Q16_16::MAX);
+    assert_eq!(q(0.).powf(q(-1.)), q(0.).inv());
+    assert_eq!(q(0.).ln(), Q16_16::MIN);
+    assert_eq!(q(20.).exp(), Q16_16::MAX);
+    assert_eq!(q(3.).hypot(q(4.)), q(5.));
+
+    // Q1.31 gets the extra precision
+    for i in -100..100 {
+        let v = Q1_31::from_f32(i as f32 / 100.);
+        let x = v.to_f64();
+        assert!(ulps(v.sin(), x.sin()) <= 16.);
+        assert!(ulps(v.atan(), x.atan()) <= 16.);
+    }
+}
+
+#[test]
+fn test_fixed_bit_exact() {
+    // golden values, the same on every platform
+    assert_eq!(q(1.).sin().to_bits(), 55147);
+    assert_eq!(q(1.).cos().to_bits(), 35409);
+    assert_eq!(q(1.).exp().to_bits(), 178145);
+    assert_eq!(q(2.).sqrt().to_bits(), 92681);
+    assert_eq!(q(10.).ln().to_bits(), 150902);
+    assert_eq!(Q1_31::from_f32(0.5).sin().to_bits(), 1029558505);
+}
+
+#[test]
+fn test_fixed_transforms() {
+    let v = rotate2(&V!([q(1.), q(0.)]), q(PI / 2.));
+    assert!(ulps(v[0], 0.) <= 2. && ulps(v[1], 1.) <= 2.);
+
+    let v = rotate3z(&V!([q(0.), q(2.), q(5.)]), q(PI));
+    assert!(ulps(v[0], 0.) <= 2. && ulps(v[1], -2.) <= 2.);
+    assert_eq!(v[2], q(5.));
+
+    let p = projection(q(90.), q(1.5), q(1.), q(100.));
+    let e = projection(90f32, 1.5, 1., 100.);
+    for (a, b) in p.iter().zip(e.iter()) {
+        assert!((a.to_f64() - *b as f64).abs() < 1e-3, "{} {}", a, b);
+    }
+
+    let m: Matrix<Q16_16> = M!([[2., 1.], [1., 3.]]).cast();
+    assert_eq!(m.determinant(), q(5.));
+    let inv = m.inverse().unwrap();
+    assert!(ulps(inv[(0, 0)], 0.6) <= 1. && ulps(inv[(0, 1)], -0.2) <= 1.);
+    assert!(m
+        .mul_mat(&inv)
+        .iter()
+        .zip([1., 0., 0., 1.])
+        .all(|(a, b)| ulps(*a, b) <= 2.));
+}
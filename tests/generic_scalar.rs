@@ -0,0 +1,190 @@
+use std::{
+    iter::Sum,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+};
+
+use matrix::{
+    angle_cos, lerp,
+    scalar::{Lerp, MulAdd},
+    ComplexField, Dot, Field, Matrix, Ring, Scalar, Semiring, Transpose,
+    Vector, M, V,
+};
+
+// a user scalar that implements the algebraic tiers, `Scalar` comes from the
+// blanket impl
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+struct Real(f64);
+
+macro_rules! binop {
+    ($tr:ident, $f:ident, $atr:ident, $af:ident, $op:tt) => {
+        impl $tr for Real {
+            type Output = Real;
+            fn $f(self, rhs: Real) -> Real {
+                Real(self.0 $op rhs.0)
+            }
+        }
+
+        impl $atr for Real {
+            fn $af(&mut self, rhs: Real) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+binop!(Add, add, AddAssign, add_assign, +);
+binop!(Sub, sub, SubAssign, sub_assign, -);
+binop!(Mul, mul, MulAssign, mul_assign, *);
+binop!(Div, div, DivAssign, div_assign, /);
+
+impl Neg for Real {
+    type Output = Real;
+    fn neg(self) -> Real {
+        Real(-self.0)
+    }
+}
+
+impl Sum for Real {
+    fn sum<I: Iterator<Item = Real>>(iter: I) -> Real {
+        iter.fold(Real(0.), |a, b| a + b)
+    }
+}
+
+impl MulAdd<Real, Real> for Real {
+    fn mul_add(self, a: &Real, b: &Real) -> Real {
+        Real(self.0.mul_add(a.0, b.0))
+    }
+}
+
+impl Lerp for Real {
+    fn lerp(u: Real, v: Real, t: f32) -> Real {
+        Real(f64::lerp(u.0, v.0, t))
+    }
+}
+
+impl Semiring for Real {
+    fn one() -> Real {
+        Real(1.)
+    }
+
+    fn is_non_zero(&self) -> bool {
+        self.0.abs() > 1e-10
+    }
+}
+
+impl Ring for Real {}
+
+impl Field for Real {
+    fn inv(self) -> Real {
+        Real(1. / self.0)
+    }
+}
+
+impl ComplexField for Real {
+    type AbsOutput = f64;
+    type TanOutput = Real;
+    type SinOutput = Real;
+    type CosOutput = Real;
+
+    fn abs(&self) -> f64 {
+        self.0.abs()
+    }
+
+    fn tan(self) -> Real {
+        Real(self.0.tan())
+    }
+
+    fn sin(self) -> Real {
+        Real(self.0.sin())
+    }
+
+    fn cos(self) -> Real {
+        Real(self.0.cos())
+    }
+
+    fn sinh(self) -> Real {
+        Real(self.0.sinh())
+    }
+
+    fn cosh(self) -> Real {
+        Real(self.0.cosh())
+    }
+
+    fn asin(self) -> Real {
+        Real(self.0.asin())
+    }
+
+    fn acos(self) -> Real {
+        Real(self.0.acos())
+    }
+
+    fn atan(self) -> Real {
+        Real(self.0.atan())
+    }
+
+    fn exp(self) -> Real {
+        Real(self.0.exp())
+    }
+
+    fn ln(self) -> Real {
+        Real(self.0.ln())
+    }
+
+    fn powf(self, n: f64) -> Real {
+        Real(self.0.powf(n))
+    }
+
+    fn powc(self, n: Real) -> Real {
+        Real(self.0.powf(n.0))
+    }
+
+    fn hypot(self, other: Real) -> f64 {
+        self.0.hypot(other.0)
+    }
+
+    fn real(self) -> f64 {
+        self.0
+    }
+}
+
+#[test]
+fn test_user_scalar_vector() {
+    let u = V!([Real(1.), Real(2.), Real(3.)]);
+    let v = V!([Real(4.), Real(5.), Real(6.)]);
+
+    assert_eq!(u.dot(&v), Real(32.));
+    assert!((angle_cos(&u, &v) - 0.974631846).abs() < 1e-6);
+
+    let w: Vector<Real> = lerp(u.clone(), v.clone(), 0.5);
+    assert!(w.iter().eq(&[Real(2.5), Real(3.5), Real(4.5)]));
+}
+
+#[test]
+fn test_user_scalar_matrix() {
+    let m: Matrix<Real> = M!([[Real(1.), Real(2.)], [Real(3.), Real(4.)]]);
+
+    assert!(m
+        .transpose()
+        .iter()
+        .eq(&[Real(1.), Real(3.), Real(2.), Real(4.)]));
+    assert!(m.conjugate_transpose().iter().eq(m.transpose().iter()));
+
+    let v = m.mul_vec(&V!([Real(1.), Real(1.)]));
+    assert!(v.iter().eq(&[Real(3.), Real(7.)]));
+    assert_eq!(m.determinant(), Real(-2.));
+
+    let half = lerp(m.clone(), m.transpose(), 0.5);
+    assert!(half.iter().eq(&[Real(1.), Real(2.5), Real(2.5), Real(4.)]));
+}
+
+#[test]
+fn test_user_scalar_is_scalar() {
+    fn trace<K: Scalar>(m: &Matrix<K>) -> Option<K> {
+        m.trace()
+    }
+
+    let m: Matrix<Real> = M!([[Real(1.), Real(2.)], [Real(3.), Real(4.)]]);
+    assert_eq!(trace(&m), Some(Real(5.)));
+}
@@ -0,0 +1,129 @@
+use matrix::{Bf16, Dot, Matrix, Semiring, Vector, F16, M};
+
+#[test]
+fn test_f16_conversion() {
+    let cases: [(f32, u16); 12] = [
+        (0., 0x0000),
+        (-0., 0x8000),
+        (1., 0x3c00),
+        (-2., 0xc000),
+        (65504., 0x7bff),
+        // halfway to the next power of two rounds up to infinity
+        (65520., 0x7c00),
+        (65519., 0x7bff),
+        (f32::INFINITY, 0x7c00),
+        // smallest subnormal and the tie below it
+        (5.9604645e-8, 0x0001),
+        (2.9802322e-8, 0x0000),
+        (6.1035156e-5, 0x0400),
+        (1. / 3., 0x3555),
+    ];
+    for (x, bits) in cases {
+        assert_eq!(F16::from_f32(x).to_bits(), bits, "{}", x);
+    }
+
+    // ties to even around 1, where the spacing is 2^-10
+    let ulp = 1. / 1024.;
+    assert_eq!(F16::from(1. + ulp / 2.).to_bits(), 0x3c00);
+    assert_eq!(F16::from(1. + 3. * ulp / 2.).to_bits(), 0x3c02);
+    assert_eq!(F16::from(1. + ulp / 2. + 1e-6).to_bits(), 0x3c01);
+    assert!(F16::from(f32::NAN).is_nan());
+
+    // every f16 survives a round trip through f32
+    for bits in 0..=u16::MAX {
+        let h = F16::from_bits(bits);
+        if !h.is_nan() {
+            assert_eq!(F16::from(h.to_f32()).to_bits(), bits);
+        }
+    }
+}
+
+#[test]
+fn test_f16_nearest() {
+    // the rounded value is never further than a neighbouring f16
+    let mut state = 1u64;
+    for _ in 0..10000 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let x = f32::from_bits((state >> 32) as u32 & 0x477f_ffff);
+        let h = F16::from(x);
+        let d = (h.to_f32() - x).abs();
+        for n in [h.to_bits().wrapping_sub(1), h.to_bits() + 1] {
+            let v = F16::from_bits(n).to_f32();
+            assert!(v.is_nan() || d <= (v - x).abs());
+        }
+    }
+}
+
+#[test]
+fn test_bf16_conversion() {
+    assert_eq!(Bf16::from(1.).to_bits(), 0x3f80);
+    assert_eq!(Bf16::from(-2.).to_bits(), 0xc000);
+    assert_eq!(Bf16::from(3.0e38).to_bits(), 0x7f62);
+    assert_eq!(Bf16::from(f32::MAX).to_bits(), 0x7f80);
+    assert_eq!(Bf16::from(f32::from_bits(0x3f80_8000)).to_bits(), 0x3f80);
+    assert_eq!(Bf16::from(f32::from_bits(0x3f81_8000)).to_bits(), 0x3f82);
+    assert_eq!(Bf16::from(f32::from_bits(0x3f80_8001)).to_bits(), 0x3f81);
+    assert!(Bf16::from(f32::NAN).is_nan());
+    assert_eq!(Bf16::from(1e-40).to_f32(), f32::from_bits(0x0001_0000));
+}
+
+#[test]
+fn test_half_arithmetic() {
+    let (a, b) = (F16::from(1.5), F16::from(0.25));
+    assert_eq!(a + b, F16::from(1.75));
+    assert_eq!(a - b, F16::from(1.25));
+    assert_eq!(a * b, F16::from(0.375));
+    assert_eq!(a / b, F16::from(6.));
+    assert_eq!(-a, F16::from(-1.5));
+    assert!(F16::from(-1.) < b);
+    assert_eq!(F16::from(0.), F16::from(-0.));
+
+    // 2048 + 1 is not representable, the spacing above 2048 is 2
+    let mut sum = F16::from(2048.);
+    sum += F16::one();
+    assert_eq!(sum, F16::from(2048.));
+    assert_eq!(Bf16::from(256.) + Bf16::one(), Bf16::from(256.));
+}
+
+#[test]
+fn test_mixed_precision_accumulation() {
+    let n = 3000;
+    let ones = Vector::from(vec![F16::one(); n]);
+    assert_eq!(ones.dot(&ones), F16::from(3000.));
+    assert_eq!(ones.iter().copied().sum::<F16>(), F16::from(3000.));
+
+    let naive = ones.iter().fold(F16::default(), |a, &b| a + b);
+    assert_eq!(naive, F16::from(2048.));
+
+    let row = Matrix::new(1, n, vec![F16::one(); n]);
+    let col = Matrix::new(n, 1, vec![F16::one(); n]);
+    assert_eq!(row.mul_mat(&col)[(0, 0)], F16::from(3000.));
+
+    let b = Matrix::new(1, 512, vec![Bf16::from(1.); 512]);
+    let c = b.mul_mat(&Matrix::new(512, 1, vec![Bf16::from(1.); 512]));
+    assert_eq!(c[(0, 0)], Bf16::from(512.));
+}
+
+#[test]
+fn test_half_matrix_cast() {
+    let m: Matrix<f32> = M!([[1., 0.1, -3.], [65504., 1e-8, 70000.]]);
+    let h = m.cast::<F16>();
+    assert_eq!(h[(0, 0)], F16::from(1.));
+    assert_eq!(h[(0, 1)].to_bits(), 0x2e66);
+    assert_eq!(h[(1, 1)].to_bits(), 0);
+    assert_eq!(h[(1, 2)].to_f32(), f32::INFINITY);
+
+    let back = h.cast::<f32>();
+    assert_eq!(back[(0, 2)], -3.);
+    assert_eq!(back[(1, 0)], 65504.);
+    assert!((back[(0, 1)] - 0.1).abs() < 1e-4);
+
+    let b = m.cast::<Bf16>().cast::<f32>();
+    assert_eq!(b[(1, 2)], 70144.);
+    assert_eq!(b.cast::<Bf16>().cast::<f32>()[(1, 2)], 70144.);
+
+    let inv = M!([[2., 0.], [0., 4.]]).cast::<F16>().inverse().unwrap();
+    assert_eq!(inv.cast::<f64>()[(1, 1)], 0.25);
+}
@@ -0,0 +1,97 @@
+use matrix::{BigInt, Matrix, M};
+
+#[test]
+fn test_integer_determinant() {
+    let u: Matrix<i64> = M!([[8, 5, -2], [4, 7, 20], [7, 6, 1]]);
+    assert_eq!(u.checked_determinant(), Some(-174));
+
+    let u: Matrix<i64> = M!([[0, 1, 2], [1, 0, 3], [4, -3, 8]]);
+    assert_eq!(u.checked_determinant(), Some(-2));
+
+    let u: Matrix<i64> = M!([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(u.checked_determinant(), Some(0));
+
+    let u: Matrix<i128> =
+        M!([[2, 0, 0, 0], [0, 3, 0, 0], [0, 0, 0, 5], [0, 0, 7, 0]]);
+    assert_eq!(u.checked_determinant(), Some(-210));
+}
+
+#[test]
+fn test_integer_determinant_overflow() {
+    let big = 1 << 40;
+    let u: Matrix<i64> = M!([[big, 0], [0, big]]);
+    assert_eq!(u.checked_determinant(), None);
+
+    let u: Matrix<i128> = M!([[big as i128, 0], [0, big as i128]]);
+    assert_eq!(u.checked_determinant(), Some(1 << 80));
+}
+
+#[test]
+fn test_integer_rank() {
+    let u: Matrix<i64> = M!([[1, 2, 0, 0], [2, 4, 0, 0], [-1, 2, 1, 1]]);
+    assert_eq!(u.checked_rank(), Some(2));
+
+    let u: Matrix<i64> = M!([[0, 0, 3], [0, 0, 6], [0, 1, 0]]);
+    assert_eq!(u.checked_rank(), Some(2));
+
+    let n = 1_000_000_007;
+    let u: Matrix<i64> = M!([[n, n + 1], [n - 1, n]]);
+    assert_eq!(u.checked_rank(), Some(2));
+    let u: Matrix<i64> = M!([[n, 2 * n], [3 * n, 6 * n]]);
+    assert_eq!(u.checked_rank(), Some(1));
+}
+
+#[test]
+fn test_integer_mul_mat() {
+    let a: Matrix<i64> = M!([[1, 2], [3, 4]]);
+    let b: Matrix<i64> = M!([[5, 6], [7, 8]]);
+    let c = a.checked_mul_mat(&b).unwrap();
+    assert!(c.iter().eq(&[19, 22, 43, 50]));
+    assert!(a.wrapping_mul_mat(&b).iter().eq(c.iter()));
+
+    let m: Matrix<i64> = M!([[i64::MAX, 1], [1, 1]]);
+    assert!(m.checked_mul_mat(&m).is_none());
+
+    let w = m.wrapping_mul_mat(&m);
+    let wide: Matrix<i128> = M!([[i64::MAX as i128, 1], [1, 1]]);
+    let exact = wide.checked_mul_mat(&wide).unwrap();
+    assert!(w.iter().zip(exact.iter()).all(|(&a, &b)| a == b as i64));
+}
+
+#[test]
+fn test_integer_adjugate() {
+    let a: Matrix<i64> = M!([[1, 2, 3], [0, 4, 5], [1, 0, 6]]);
+    let adj = a.checked_adjugate().unwrap();
+    assert!(adj.iter().eq(&[24, -12, -2, 5, 3, -5, -4, 2, 4]));
+
+    let det = a.checked_determinant().unwrap();
+    let id = a.checked_mul_mat(&adj).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert_eq!(id[(i, j)], if i == j { det } else { 0 });
+        }
+    }
+
+    // rank n - 1 matrices still have a non-zero adjugate
+    let s: Matrix<i64> = M!([[1, 2], [2, 4]]);
+    assert!(s.checked_adjugate().unwrap().iter().eq(&[4, -2, -2, 1]));
+
+    let big: Matrix<i64> = M!([[i64::MAX, 1, 0], [1, i64::MAX, 0], [0, 0, 1]]);
+    assert!(big.checked_adjugate().is_none());
+}
+
+#[test]
+fn test_bigint_bareiss() {
+    let big: Matrix<BigInt> = Matrix::new(
+        2,
+        2,
+        vec![
+            BigInt::from(1i64 << 40),
+            BigInt::from(0),
+            BigInt::from(0),
+            BigInt::from(1i64 << 40),
+        ],
+    );
+    assert_eq!(big.checked_determinant(), Some(BigInt::from(1i128 << 80)));
+    assert_eq!(big.checked_rank(), Some(2));
+}
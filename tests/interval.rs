@@ -0,0 +1,209 @@
+use std::f64::consts::PI;
+
+use matrix::{
+    scalar::Sqrt, ComplexField, Interval, Matrix, Semiring, Vector, M, V,
+};
+
+fn iv(lo: f64, hi: f64) -> Interval {
+    Interval::new(lo, hi)
+}
+
+// deterministic uniform samples in [0, 1)
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> f64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn sample(&mut self, x: &Interval) -> f64 {
+        x.lo() + (x.hi() - x.lo()) * self.next()
+    }
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let s = Interval::around(0.1) + Interval::around(0.2);
+    assert!(s.contains(0.3));
+    assert!(!Interval::point(0.1 + 0.2).contains(0.3));
+
+    let p = iv(-1., 2.) * iv(3., 4.);
+    assert!(p.contains(-4.) && p.contains(8.));
+    assert!(p.is_subset_of(&iv(-4.0001, 8.0001)));
+
+    assert_eq!(iv(1., 2.) / iv(-1., 1.), Interval::entire());
+    assert!((iv(1., 2.) / iv(4., 8.)).contains(0.125));
+    assert_eq!(-iv(1., 2.), iv(-2., -1.));
+    assert_eq!(iv(-3., 2.).abs(), iv(0., 3.));
+
+    assert!(iv(1., 2.) < iv(3., 4.));
+    assert_eq!(iv(1., 3.).partial_cmp(&iv(2., 4.)), None);
+    assert!(iv(1., 2.).is_non_zero());
+    assert!(!iv(-1., 2.).is_non_zero());
+    assert_eq!(iv(0., 2.).intersect(&iv(3., 4.)), None);
+    assert_eq!(iv(0., 2.).hull(&iv(3., 4.)), iv(0., 4.));
+}
+
+// interval extension of a real function, paired with the function
+type Enclosure = (fn(Interval) -> Interval, fn(f64) -> f64);
+
+#[test]
+fn test_interval_functions() {
+    let s = iv(0., PI).sin();
+    assert_eq!(s.hi(), 1.);
+    assert!(s.contains(0.));
+    assert_eq!(iv(-0.1, 0.1).cos().hi(), 1.);
+    assert_eq!(iv(0., 7.).cos(), iv(-1., 1.));
+    assert_eq!(iv(1., 2.).tan(), Interval::entire());
+    assert!(iv(4., 9.).sqrt().contains(2.) && iv(4., 9.).sqrt().contains(3.));
+    assert!(iv(-2., 1.)
+        .powf(Interval::point(2.))
+        .is_subset_of(&iv(0., 4.0001)));
+    assert!(iv(-2., 1.).powf(Interval::point(3.)).contains(-8.));
+    assert!(Interval::point(2.)
+        .powf(Interval::point(0.5))
+        .contains(2f64.sqrt()));
+
+    // every function image encloses the images of sampled points
+    let mut rng = Lcg(7);
+    let funcs: [Enclosure; 11] = [
+        (Interval::sin, f64::sin),
+        (Interval::cos, f64::cos),
+        (Interval::tan, f64::tan),
+        (Interval::exp, f64::exp),
+        (Interval::sinh, f64::sinh),
+        (Interval::cosh, f64::cosh),
+        (Interval::atan, f64::atan),
+        (|x| x.abs(), f64::abs),
+        (|x| x.hypot(Interval::point(1.)), |x| x.hypot(1.)),
+        (|x| x * x - x, |x| x * x - x),
+        (|x| x.powf(Interval::point(3.)), |x| x.powi(3)),
+    ];
+    for _ in 0..200 {
+        let a = rng.next() * 20. - 10.;
+        let x = iv(a, a + rng.next() * 3.);
+        for (f, g) in &funcs {
+            let y = f(x);
+            for _ in 0..5 {
+                let t = rng.sample(&x);
+                assert!(y.contains(g(t)), "{:?} does not contain {}", y, g(t));
+            }
+        }
+
+        let x = iv(a.abs() * 0.1 + 0.01, a.abs() * 0.1 + 0.5);
+        for t in [x.lo(), x.hi(), rng.sample(&x)] {
+            assert!(x.ln().contains(t.ln()));
+            assert!(x.sqrt().contains(t.sqrt()));
+        }
+    }
+}
+
+#[test]
+fn test_interval_linear_algebra() {
+    // Hilbert matrix of order 6, its exact determinant is 1 / 186313420339200000
+    let h = Matrix::new(
+        6,
+        6,
+        (0..36)
+            .map(|k| {
+                Interval::point(1.)
+                    / Interval::point((k / 6 + k % 6 + 1) as f64)
+            })
+            .collect(),
+    );
+    let det = h.determinant();
+    assert!(det.contains(1. / 186313420339200000.));
+    assert!(det.width() < 1e-10);
+
+    let v = V!([Interval::around(3.), Interval::around(4.)]);
+    assert!(v.norm().contains(5.));
+    assert!(v.norm_1().contains(7.));
+
+    let m = M!([[iv(1., 1.), iv(2., 2.)], [iv(0., 0.5), iv(1., 1.)]]);
+    let w = m.mul_vec(&V!([Interval::point(1.), Interval::point(1.)]));
+    assert_eq!(w[0], Interval::point(3.));
+    assert_eq!(w[1], iv(1., 1.5));
+}
+
+// solution of one point system, by Cramer's rule
+fn solve2(a: [[f64; 2]; 2], b: [f64; 2]) -> [f64; 2] {
+    let d = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+    [
+        (b[0] * a[1][1] - a[0][1] * b[1]) / d,
+        (a[0][0] * b[1] - b[0] * a[1][0]) / d,
+    ]
+}
+
+#[test]
+fn test_interval_solve() {
+    let e = 0.05;
+    let a = M!([
+        [iv(4. - e, 4. + e), iv(1. - e, 1. + e)],
+        [iv(1. - e, 1. + e), iv(3. - e, 3. + e)]
+    ]);
+    let b = V!([iv(1. - e, 1. + e), iv(2. - e, 2. + e)]);
+
+    assert!(a.certify_nonsingular());
+    let k = a.krawczyk_solve(&b).unwrap();
+    let g = a.gauss_solve(&b).unwrap();
+
+    let mut rng = Lcg(11);
+    for _ in 0..500 {
+        let pa = [
+            [rng.sample(&a[(0, 0)]), rng.sample(&a[(0, 1)])],
+            [rng.sample(&a[(1, 0)]), rng.sample(&a[(1, 1)])],
+        ];
+        let pb = [rng.sample(&b[0]), rng.sample(&b[1])];
+        let x = solve2(pa, pb);
+        for i in 0..2 {
+            assert!(k[i].contains(x[i]));
+            assert!(g[i].contains(x[i]));
+        }
+    }
+
+    // the point solution 1/11, 7/11 is enclosed tightly
+    let p = a.map(|x| Interval::point(x.mid()));
+    let q = b.map(|x| Interval::point(x.mid()));
+    let x = p.krawczyk_solve(&q).unwrap();
+    assert!(x[0].contains(1. / 11.) && x[1].contains(7. / 11.));
+    assert!(x.iter().all(|v| v.width() < 1e-12));
+}
+
+#[test]
+fn test_interval_singular() {
+    let s = M!([[iv(1., 1.), iv(2., 2.)], [iv(2., 2.), iv(4., 4.)]]);
+    assert!(!s.certify_nonsingular());
+    assert!(s.krawczyk_solve(&V!([iv(1., 1.), iv(1., 1.)])).is_none());
+
+    // contains the singular matrix [[1, 1], [1, 1]]
+    let w = M!([[iv(1., 2.), iv(0.5, 1.)], [iv(0.5, 1.), iv(1., 2.)]]);
+    assert!(!w.certify_nonsingular());
+    assert!(w.determinant().contains(0.));
+
+    let r: Matrix<Interval> =
+        M!([[iv(2., 3.), iv(0., 0.5)], [iv(-0.5, 0.), iv(2., 3.)]]);
+    assert!(r.certify_nonsingular());
+    let v: Vector<Interval> = V!([iv(1., 1.), iv(0., 0.)]);
+    assert!(r.gauss_solve(&v).is_some());
+}
+
+#[test]
+fn test_interval_nan() {
+    let nan = Interval::point(f64::NAN);
+    for x in [nan, Interval::point(f64::INFINITY)] {
+        for y in [x.sin(), x.cos()] {
+            assert!(y.contains(-1.) && y.contains(1.), "{:?}", y);
+        }
+    }
+
+    // NaN entries can not serve as pivots and spread through elimination
+    let b: Vector<Interval> = V!([iv(1., 1.), iv(1., 1.)]);
+    let a = M!([[nan, iv(1., 1.)], [nan, iv(1., 1.)]]);
+    assert!(a.gauss_solve(&b).is_none());
+    let a = M!([[nan, iv(1., 1.)], [iv(2., 2.), iv(1., 1.)]]);
+    assert!(a.gauss_solve(&b).is_none());
+}
@@ -1,21 +1,20 @@
-use matrix::{approx_eq, Complex, Matrix, Scalar, C, M};
+use matrix::{approx_eq, Complex, Matrix, C, M};
 
 #[test]
 fn test_inverse() {
     let u = M!([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
     let a = u.inverse().unwrap();
-    assert!(a._d.eq(&[1., 0., 0., 0., 1., 0., 0., 0., 1.,]));
+    assert!(a.iter().eq(&[1., 0., 0., 0., 1., 0., 0., 0., 1.,]));
 
     let u = M!([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]);
     let a = u.inverse().unwrap();
 
-    assert!(a._d.eq(&[0.5, 0., 0., 0., 0.5, 0., 0., 0., 0.5]));
+    assert!(a.iter().eq(&[0.5, 0., 0., 0., 0.5, 0., 0., 0., 0.5]));
 
     let u = M!([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
     let a = u.inverse().unwrap();
 
     assert!(a
-        ._d
         .iter()
         .zip(&[
             0.649425287_f64,
@@ -36,7 +35,6 @@ fn test_inverse_complex() {
     let u = M!([[C!(1., -1.), C!(2., 3.)], [C!(4., 5.), C!(6., 7.)]]);
     let a = u.inverse().unwrap();
     assert!(a
-        ._d
         .iter()
         .zip(&[
             C!(-0.03210463733650415, 0.3162901307966706),
@@ -49,7 +47,6 @@ fn test_inverse_complex() {
     let u = M!([[C!(1., 0.), C!(2., 0.)], [C!(3., 0.), C!(4., 0.)]]);
     let a = u.inverse().unwrap();
     assert!(a
-        ._d
         .iter()
         .zip(&[C!(-2., 0.), C!(1., 0.), C!(1.5, 0.), C!(-0.5, -0.),])
         .all(|(x, y)| approx_eq!(*x, *y)));
@@ -57,7 +54,6 @@ fn test_inverse_complex() {
     let u = M!([[C!(1., 1.), C!(2., 2.)], [C!(3., 3.), C!(4., 4.)]]);
     let a = u.inverse().unwrap();
     assert!(a
-        ._d
         .iter()
         .zip(&[C!(-1., 1.), C!(0.5, -0.5), C!(0.75, -0.75), C!(-0.25, 0.25),])
         .all(|(x, y)| approx_eq!(*x, *y)));
@@ -65,7 +61,6 @@ fn test_inverse_complex() {
     let u = M!([[C!(1., 2.), C!(3., 4.)], [C!(5., 6.), C!(7., 8.)]]);
     let a = u.inverse().unwrap();
     assert!(a
-        ._d
         .iter()
         .zip(&[
             C!(-0.5, 0.4375),
@@ -0,0 +1,110 @@
+use matrix::{Complex, Layout, Matrix, Vector, C, M, V};
+
+#[test]
+fn test_matrix_iter() {
+    let mut mat = M!([[1., 2., 3.], [4., 5., 6.]]);
+    assert!(mat.iter().eq(&[1., 2., 3., 4., 5., 6.]));
+
+    for v in mat.iter_mut() {
+        *v *= 2.;
+    }
+    assert!(mat.iter().eq(&[2., 4., 6., 8., 10., 12.]));
+
+    let rows: Vec<Vec<f64>> =
+        mat.rows().map(|r| r.copied().collect()).collect();
+    assert_eq!(rows, vec![vec![2., 4., 6.], vec![8., 10., 12.]]);
+
+    let cols: Vec<Vec<f64>> =
+        mat.cols().map(|c| c.copied().collect()).collect();
+    assert_eq!(cols, vec![vec![2., 8.], vec![4., 10.], vec![6., 12.]]);
+
+    let col_major = mat.to_layout(Layout::ColMajor);
+    let rows: Vec<Vec<f64>> =
+        col_major.rows().map(|r| r.copied().collect()).collect();
+    assert_eq!(rows, vec![vec![2., 4., 6.], vec![8., 10., 12.]]);
+}
+
+#[test]
+fn test_matrix_enumerate_indexed() {
+    let mat = M!([[1., 2.], [3., 4.]]);
+    for layout in [Layout::RowMajor, Layout::ColMajor] {
+        let mat = mat.to_layout(layout);
+        for ((i, j), v) in mat.enumerate_indexed() {
+            assert_eq!(*v, mat[(i, j)]);
+        }
+        assert_eq!(mat.enumerate_indexed().count(), 4);
+    }
+}
+
+#[test]
+fn test_matrix_map_zip_fold() {
+    let a = M!([[1., 2.], [3., 4.]]);
+    let b = M!([[10., 20.], [30., 40.]]).to_layout(Layout::ColMajor);
+
+    let sq = a.map(|x| x * x);
+    assert!(sq.iter().eq(&[1., 4., 9., 16.]));
+
+    let sum = a.zip_map(&b, |x, y| x + y);
+    assert!(sum.iter().eq(&[11., 22., 33., 44.]));
+
+    let flags: Matrix<bool> = a.map(|x| x > 2.);
+    assert!(flags.iter().eq(&[false, false, true, true]));
+
+    assert_eq!(a.fold(0., |acc, x| acc + x), 10.);
+    assert_eq!(a.fold(f64::MIN, f64::max), 4.);
+}
+
+#[test]
+#[should_panic(expected = "matrices must be the same size")]
+fn test_matrix_zip_map_shape() {
+    let a = M!([[1., 2.], [3., 4.]]);
+    let b = M!([[1., 2.]]);
+    let _ = a.zip_map(&b, |x, y| x * y);
+}
+
+#[test]
+fn test_matrix_cast() {
+    let a = M!([[1.5_f32, 2.], [3., 4.]]);
+    let b = a.cast::<f64>();
+    assert!(b.iter().eq(&[1.5_f64, 2., 3., 4.]));
+    assert!(b.cast::<f32>().iter().eq(a.iter()));
+
+    let c = b.cast::<Complex>();
+    assert!(c
+        .iter()
+        .eq(&[C!(1.5, 0.), C!(2., 0.), C!(3., 0.), C!(4., 0.)]));
+    assert!(a.cast::<Complex>().iter().eq(c.iter()));
+}
+
+#[test]
+fn test_matrix_new() {
+    let a = Matrix::new(2, 3, vec![1., 2., 3., 4., 5., 6.]);
+    assert_eq!(a.shape(), (2, 3));
+    assert_eq!(a[(1, 0)], 4.);
+}
+
+#[test]
+#[should_panic(expected = "data must match the matrix size")]
+fn test_matrix_new_bad_size() {
+    let _ = Matrix::new(2, 2, vec![1., 2., 3.]);
+}
+
+#[test]
+fn test_vector_iter() {
+    let mut u = V!([1., 2., 3.]);
+    assert!(u.iter().eq(&[1., 2., 3.]));
+
+    for v in u.iter_mut() {
+        *v += 1.;
+    }
+    u[0] = 0.;
+    assert!(u.iter().eq(&[0., 3., 4.]));
+
+    let v = V!([1., 1., 1.]);
+    assert!(u.map(|x| -x).iter().eq(&[-0., -3., -4.]));
+    assert!(u.zip_map(&v, |x, y| x - y).iter().eq(&[-1., 2., 3.]));
+    assert_eq!(u.fold(0., |acc, x| acc + x), 7.);
+
+    let c: Vector<Complex> = u.cast();
+    assert!(c.iter().eq(&[C!(0., 0.), C!(3., 0.), C!(4., 0.)]));
+}
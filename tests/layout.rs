@@ -0,0 +1,102 @@
+use matrix::{Complex, Layout, Matrix, Transpose, Vector, C, M, V};
+
+#[test]
+fn test_layout_conversion() {
+    let mat = M!([[1., 2., 3.], [4., 5., 6.]]);
+    assert_eq!(mat.layout(), Layout::RowMajor);
+
+    let col = mat.to_layout(Layout::ColMajor);
+    assert_eq!(col.layout(), Layout::ColMajor);
+    assert_eq!(col.shape(), (2, 3));
+    assert!(col.iter().eq(&[1., 4., 2., 5., 3., 6.]));
+    assert_eq!(col[(1, 2)], 6.);
+    assert_eq!(col[(0, 1)], 2.);
+
+    let row = col.into_layout(Layout::RowMajor);
+    assert!(row.iter().eq(mat.iter()));
+}
+
+#[test]
+fn test_into_transpose() {
+    let mat = M!([[1., 2., 3.], [4., 5., 6.]]);
+    let t = mat.clone().into_transpose();
+    assert_eq!(t.shape(), (3, 2));
+    assert_eq!(t.layout(), Layout::ColMajor);
+    assert!(t.iter().eq(mat.iter()));
+    for i in 0..3 {
+        for j in 0..2 {
+            assert_eq!(t[(i, j)], mat[(j, i)]);
+        }
+    }
+    assert!(t
+        .to_layout(Layout::RowMajor)
+        .iter()
+        .eq(mat.transpose().iter()));
+
+    let back = t.into_transpose();
+    assert_eq!(back.layout(), Layout::RowMajor);
+    assert!(back.iter().eq(mat.iter()));
+
+    let mat = M!([[C!(1., 2.), C!(3., 4.)]]);
+    let t = mat.into_transpose();
+    assert_eq!(t[(1, 0)], C!(3., 4.));
+}
+
+#[test]
+fn test_col_major_operations() {
+    let a = M!([[1., 2.], [3., 4.]]);
+    let b = M!([[5., 6.], [7., 8.]]);
+    let a_col = a.to_layout(Layout::ColMajor);
+
+    let sum = a_col.clone() + b.clone();
+    assert_eq!(sum.layout(), Layout::ColMajor);
+    assert!(sum
+        .to_layout(Layout::RowMajor)
+        .iter()
+        .eq(&[6., 8., 10., 12.]));
+
+    let diff = b.clone() - a_col.clone();
+    assert!(diff.iter().eq(&[4., 4., 4., 4.]));
+
+    let prod = a_col.mul_mat(&b);
+    assert!(prod.iter().eq(a.mul_mat(&b).iter()));
+
+    let v: Vector<f64> = a_col.mul_vec(&V!([1., 1.]));
+    assert!(v[0] == 3. && v[1] == 7.);
+
+    assert_eq!(a_col.trace(), Some(5.));
+    assert_eq!(a_col.determinant(), -2.);
+    assert!(a_col
+        .inverse()
+        .unwrap()
+        .iter()
+        .eq(a.inverse().unwrap().iter()));
+    assert_eq!(a_col.rank(), 2);
+    assert!(a_col.row_echelon().iter().eq(a.row_echelon().iter()));
+    assert_eq!(format!("{:?}", a_col), format!("{:?}", a));
+}
+
+#[test]
+fn test_row_accessor() {
+    let a = M!([[1., 2., 3.], [4., 5., 6.]]);
+    let t = a.clone().into_transpose();
+    assert!(t.row(2).eq(&[3., 6.]));
+    assert!(t.col(1).eq(&a[1]));
+    for (i, row) in a.rows().enumerate() {
+        assert!(row.eq(a.to_layout(Layout::ColMajor).row(i)));
+    }
+}
+
+#[test]
+#[should_panic(expected = "matrix must be row-major")]
+fn test_col_major_row_index() {
+    let a: Matrix<f64> = M!([[1., 2.], [3., 4.]]).into_transpose();
+    let _ = &a[0];
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_index_out_of_bounds() {
+    let a = M!([[1., 2.], [3., 4.]]);
+    let _ = a[(0, 2)];
+}
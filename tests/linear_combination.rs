@@ -9,13 +9,13 @@ fn test_linear_combination() {
     let v2 = V!([0., 10., -100.]);
 
     let a = linear_combination(&[&e1, &e2, &e3], &[10., -2., 0.5]);
-    assert!(a._d.iter().eq(&[10., -2., 0.5]));
+    assert!(a.iter().eq(&[10., -2., 0.5]));
 
     let b = linear_combination(&[&v1, &v2], &[10., -2.]);
-    assert!(b._d.iter().eq(&[10., 0., 230.]));
+    assert!(b.iter().eq(&[10., 0., 230.]));
 
     let c = linear_combination(&[&e1, &v1, &v2], &[10., -2., 0.5]);
-    assert!(c._d.iter().eq(&[8., 1., -56.,]));
+    assert!(c.iter().eq(&[8., 1., -56.,]));
 }
 
 #[test]
@@ -64,15 +64,14 @@ fn test_linear_combination_vector_complex_numbers() {
         &[&e1, &e2, &e3],
         &[C!(10., 0.), C!(-2., 0.), C!(0.5, 0.)],
     );
-    assert_eq!(
-        a._d,
-        (e1 * C!(10., 0.) + e2 * C!(-2., 0.) + e3 * C!(0.5, 0.))._d,
+    assert!(
+        a.iter()
+            .eq((e1 * C!(10., 0.) + e2 * C!(-2., 0.) + e3 * C!(0.5, 0.)).iter()),
         "linear combination of complex vectors failed"
     );
     let b = linear_combination(&[&v1, &v2], &[C!(10., 0.), C!(-2., 0.)]);
-    assert_eq!(
-        b._d,
-        (v1 * C!(10., 0.) + v2 * C!(-2., 0.))._d,
+    assert!(
+        b.iter().eq((v1 * C!(10., 0.) + v2 * C!(-2., 0.)).iter()),
         "linear combination of complex vectors failed"
     );
 }
@@ -0,0 +1,140 @@
+use matrix::{BigInt, ComplexField, Matrix, Rational, M};
+
+type Q = Rational;
+
+fn q(num: i64, den: i64) -> Q {
+    Q::new(num, den)
+}
+
+fn dot(a: &[Q], b: &[Q]) -> Q {
+    a.iter().zip(b).map(|(x, y)| *x * *y).sum()
+}
+
+// size reduced and satisfying the Lovasz condition
+fn is_reduced(b: &Matrix<Q>, delta: Q) -> bool {
+    let (n, m) = b.shape();
+    let mut star: Vec<Vec<Q>> = Vec::new();
+    let mut mu = vec![vec![Q::default(); n]; n];
+    for i in 0..n {
+        let mut s = b[i].to_vec();
+        for j in 0..i {
+            mu[i][j] = dot(&b[i], &star[j]) / dot(&star[j], &star[j]);
+            for k in 0..m {
+                s[k] -= mu[i][j] * star[j][k];
+            }
+        }
+        star.push(s);
+    }
+
+    let half = q(1, 2);
+    let size = (0..n).all(|i| (0..i).all(|j| mu[i][j].abs() <= half));
+    let lovasz = (1..n).all(|k| {
+        let (l, p) = (dot(&star[k], &star[k]), dot(&star[k - 1], &star[k - 1]));
+        l >= (delta - mu[k][k - 1] * mu[k][k - 1]) * p
+    });
+    size && lovasz
+}
+
+fn check(b: &Matrix<i64>, delta: Q) -> Matrix<i64> {
+    let (r, u) = b.checked_lll(delta).unwrap();
+    assert!(matches!(u.checked_determinant(), Some(1) | Some(-1)));
+    assert!(u.checked_mul_mat(b).unwrap().iter().eq(r.iter()), "U B");
+    assert!(is_reduced(&r.map(Q::from), delta), "reduced basis");
+    r
+}
+
+#[test]
+fn test_lll() {
+    let b: Matrix<i64> = M!([[1, 1, 1], [-1, 0, 2], [3, 5, 6]]);
+    let r = check(&b, q(3, 4));
+    // mu = -1/2 on the last vector, ties round up
+    assert!(r.iter().eq(&[0, 1, 0, 1, 0, 1, -2, 0, 1]));
+
+    let b: Matrix<i64> = M!([
+        [1, 0, 0, 0, 0, 13],
+        [0, 1, 0, 0, 0, 287],
+        [0, 0, 1, 0, 0, 1019],
+        [0, 0, 0, 1, 0, 4403],
+        [0, 0, 0, 0, 1, 9999],
+    ]);
+    for delta in [q(1, 2), q(3, 4), q(99, 100), q(1, 1)] {
+        check(&b, delta);
+    }
+
+    // a skewed basis of Z^3 comes back as unit vectors
+    let b: Matrix<i64> = M!([[5, 8, 13], [3, 5, 8], [2, 3, 6]]);
+    assert_eq!(b.checked_determinant(), Some(1));
+    let r = check(&b, q(3, 4));
+    assert!((0..3).all(|i| r[i].iter().map(|v| v * v).sum::<i64>() == 1));
+}
+
+#[test]
+fn test_lll_knapsack() {
+    // a subset sum instance, the solution 1 0 1 1 0 shows up as a short
+    // vector of the Lagarias-Odlyzko lattice
+    let w = [366, 385, 392, 401, 422];
+    let s = 366 + 392 + 401;
+    let n = w.len();
+    let big = 10;
+    let mut vec = Vec::new();
+    for (i, wi) in w.iter().enumerate() {
+        vec.extend((0..n).map(|j| if i == j { 2 } else { 0 }));
+        vec.push(big * wi);
+    }
+    vec.extend(std::iter::repeat_n(1, n));
+    vec.push(big * s);
+    let b = Matrix::new(n + 1, n + 1, vec);
+
+    let r = check(&b, q(99, 100));
+    // v_i = 2 x_i - 1 up to sign
+    let subset = |v: &[i64], sign: i64| -> i64 {
+        v.iter().zip(&w).map(|(v, w)| (1 + sign * v) / 2 * w).sum()
+    };
+    let found = (0..n + 1).any(|i| {
+        r[i][n] == 0
+            && r[i][..n].iter().all(|v| v.abs() == 1)
+            && (subset(&r[i][..n], 1) == s || subset(&r[i][..n], -1) == s)
+    });
+    assert!(found, "subset sum solution");
+}
+
+#[test]
+fn test_lll_rational() {
+    let b = M!([[q(1, 2), q(1, 3)], [q(3, 2), q(5, 3)]]);
+    let (r, u) = b.checked_lll(q(3, 4)).unwrap();
+    assert!(is_reduced(&r, q(3, 4)));
+    assert!(matches!(u.checked_determinant(), Some(1) | Some(-1)));
+    let ub = u.map(Q::from).mul_mat(&b);
+    assert!(ub.iter().eq(r.iter()));
+
+    // a rational basis is a scaled integer one
+    let scaled = check(&M!([[3, 2], [9, 10]]), q(3, 4));
+    assert!(r.iter().eq(scaled.map(|v| q(v, 6)).iter()));
+}
+
+#[test]
+fn test_lll_overflow() {
+    let big = 1 << 40;
+    let b: Matrix<i64> = M!([[big + 1, big], [big, big - 1]]);
+    assert!(b.checked_lll(q(3, 4)).is_none());
+
+    let b = b.map(BigInt::from);
+    let (r, _) = b.checked_lll(Rational::new(3.into(), 4.into())).unwrap();
+    let len = |i: usize| &r[i][0] * &r[i][0] + &r[i][1] * &r[i][1];
+    assert!(len(0) <= len(1));
+    assert_eq!(len(0), BigInt::from(1));
+}
+
+#[test]
+#[should_panic(expected = "delta must lie in (1/4, 1]")]
+fn test_lll_bad_delta() {
+    let b: Matrix<i64> = M!([[1, 0], [0, 1]]);
+    let _ = b.checked_lll(q(1, 4));
+}
+
+#[test]
+#[should_panic(expected = "basis vectors must be linearly independent")]
+fn test_lll_dependent() {
+    let b: Matrix<i64> = M!([[1, 2], [2, 4]]);
+    let _ = b.checked_lll(q(3, 4));
+}
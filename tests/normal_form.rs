@@ -0,0 +1,120 @@
+use matrix::{BigInt, Matrix, M};
+
+fn is_unimodular(u: &Matrix<i64>) -> bool {
+    matches!(u.checked_determinant(), Some(1) | Some(-1))
+}
+
+// D diagonal, non negative and each entry divides the next
+fn check_smith(a: &Matrix<i64>) -> Vec<i64> {
+    let (d, u, v) = a.checked_smith_normal_form().unwrap();
+    assert!(
+        is_unimodular(&u) && is_unimodular(&v),
+        "unimodular transforms"
+    );
+    let uav = u.checked_mul_mat(a).unwrap().checked_mul_mat(&v).unwrap();
+    assert!(uav.iter().eq(d.iter()), "U A V = D");
+
+    let (rows, cols) = d.shape();
+    for i in 0..rows {
+        for j in 0..cols {
+            assert!(i == j || d[(i, j)] == 0, "D must be diagonal");
+        }
+    }
+    let diag: Vec<i64> = (0..rows.min(cols)).map(|i| d[(i, i)]).collect();
+    for w in diag.windows(2) {
+        assert!(w[0] >= 0, "non negative diagonal");
+        assert!(
+            w[1] == 0 || (w[0] != 0 && w[1] % w[0] == 0),
+            "divisibility chain"
+        );
+    }
+    diag
+}
+
+fn check_hermite(a: &Matrix<i64>) -> Matrix<i64> {
+    let (h, u) = a.checked_hermite_normal_form().unwrap();
+    assert!(is_unimodular(&u), "unimodular transform");
+    assert!(u.checked_mul_mat(a).unwrap().iter().eq(h.iter()), "U A = H");
+
+    // pivots move right, are positive and reduce the entries above them
+    let (rows, cols) = h.shape();
+    let mut last = None;
+    for i in 0..rows {
+        let Some(p) = (0..cols).find(|&j| h[(i, j)] != 0) else {
+            assert!((i..rows).all(|r| (0..cols).all(|j| h[(r, j)] == 0)));
+            break;
+        };
+        assert!(last.is_none_or(|l| p > l), "row echelon form");
+        assert!(h[(i, p)] > 0, "positive pivot");
+        assert!((0..i).all(|r| (0..h[(i, p)]).contains(&h[(r, p)])));
+        last = Some(p);
+    }
+    h
+}
+
+#[test]
+fn test_smith_normal_form() {
+    let a: Matrix<i64> = M!([[2, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+    assert_eq!(check_smith(&a), [2, 6, 12]);
+
+    // Z^2 / <(2, 0), (0, 3)> is cyclic of order 6
+    let a: Matrix<i64> = M!([[2, 0], [0, 3]]);
+    assert_eq!(check_smith(&a), [1, 6]);
+
+    let a: Matrix<i64> = M!([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(check_smith(&a), [1, 3, 0]);
+
+    let a: Matrix<i64> = M!([[6, 4, 10, 0], [0, 2, 8, -2]]);
+    assert_eq!(check_smith(&a), [2, 2]);
+
+    let a: Matrix<i64> = M!([[0, 0], [0, 0], [0, 0]]);
+    assert_eq!(check_smith(&a), [0, 0]);
+
+    // the product of the invariant factors is |det|
+    let a: Matrix<i64> =
+        M!([[3, -1, 4, 1], [5, 9, -2, 6], [5, 3, 5, -8], [9, 7, 9, 3]]);
+    let det = a.checked_determinant().unwrap();
+    assert_eq!(check_smith(&a).iter().product::<i64>(), det.abs());
+}
+
+#[test]
+fn test_hermite_normal_form() {
+    let a: Matrix<i64> = M!([[2, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+    let h = check_hermite(&a);
+    assert!(h.iter().eq(&[1, 0, 50, -11, 0, 3, 28, -2, 0, 0, 61, -13]));
+
+    let a: Matrix<i64> = M!([[3, 3, 1, 4], [0, 1, 0, 0], [0, 0, 19, 16]]);
+    check_hermite(&a);
+
+    let a: Matrix<i64> = M!([[-4, 6], [6, -9], [2, -3]]);
+    let h = check_hermite(&a);
+    assert!(h.iter().eq(&[2, -3, 0, 0, 0, 0]));
+
+    // a lattice basis and any other basis of it share the same form
+    let b: Matrix<i64> = M!([[1, 2, 3], [0, 4, 5], [0, 0, 6]]);
+    let w: Matrix<i64> = M!([[2, 1, 0], [1, 1, 0], [0, 3, 1]]);
+    let other = w.checked_mul_mat(&b).unwrap();
+    assert!(check_hermite(&b).iter().eq(check_hermite(&other).iter()));
+}
+
+#[test]
+fn test_normal_form_overflow() {
+    let a: Matrix<i64> = M!([[i64::MAX, i64::MAX - 1]]);
+    assert!(a.checked_smith_normal_form().is_none());
+
+    let wide: Matrix<i128> = M!([[i64::MAX as i128, i64::MAX as i128 - 1]]);
+    let (d, _, v) = wide.checked_smith_normal_form().unwrap();
+    assert!(d.iter().eq(&[1, 0]));
+    assert_eq!(v.checked_determinant().map(|d| d.abs()), Some(1));
+
+    let a: Matrix<i64> = M!([[i64::MAX], [i64::MAX - 1]]);
+    assert!(a.checked_hermite_normal_form().is_none());
+
+    // arbitrary precision never overflows
+    let big: Matrix<BigInt> =
+        M!([[i64::MAX], [i64::MAX - 1]]).map(BigInt::from);
+    let (h, u) = big.checked_hermite_normal_form().unwrap();
+    assert_eq!(h[(0, 0)], BigInt::from(1));
+    assert_eq!(h[(1, 0)], BigInt::from(0));
+    assert!(u.checked_mul_mat(&big).unwrap().iter().eq(h.iter()));
+}
@@ -0,0 +1,143 @@
+use matrix::{
+    angle_cos, approx_eq, orthonormalize, Complex, ComplexField, Dot, Matrix,
+    Rational, Transpose, Vector, C, M, V,
+};
+
+const TOL: f64 = 1e-12;
+
+// largest deviation of the Gram matrix from the identity
+fn loss<K: ComplexField<AbsOutput = f64>>(q: &[Vector<K>]) -> f64 {
+    let mut worst = 0f64;
+    for (i, a) in q.iter().enumerate() {
+        for (j, b) in q.iter().enumerate() {
+            let d = a.dot(b) - if i == j { K::one() } else { K::default() };
+            worst = worst.max(d.abs());
+        }
+    }
+    worst
+}
+
+#[test]
+fn test_orthonormalize() {
+    let (a, b, c) = (V!([1., 1., 0.]), V!([1., 0., 1.]), V!([0., 1., 1.]));
+    let q = orthonormalize(&[&a, &b, &c], false, TOL).unwrap();
+    assert_eq!(q.len(), 3);
+    assert!(loss(&q) < 1e-15);
+    assert!(q.iter().all(|v| approx_eq!(v.norm(), 1.)));
+
+    // each vector stays in the span of the inputs up to it
+    assert!(approx_eq!(angle_cos(&q[0], &a), 1.));
+    let t = V!([1., -1., 2.]);
+    assert!(approx_eq!(angle_cos(&q[1], &t), 1.));
+    assert!(approx_eq!(q[1].dot(&a), 0.));
+
+    // fewer vectors than the dimension
+    let (s, t) = (V!([3., 0., 4., 0.]), V!([1., 1., 1., 1.]));
+    let q = orthonormalize(&[&s, &t], true, TOL).unwrap();
+    assert!(q[0]
+        .iter()
+        .zip([0.6, 0., 0.8, 0.])
+        .all(|(x, y)| approx_eq!(*x, y)));
+    assert!(loss(&q) < 1e-15);
+    assert!(orthonormalize::<f64>(&[], false, TOL).unwrap().is_empty());
+}
+
+#[test]
+fn test_orthonormalize_dependent() {
+    let (a, b) = (V!([1., 2., 3.]), V!([-2., -4., -6.]));
+    assert!(orthonormalize(&[&a, &b], false, TOL).is_err());
+    assert!(orthonormalize(&[&a, &V!([0., 0., 0.])], true, TOL).is_err());
+
+    let c = V!([1., 0., 0.]);
+    let d = V!([3., 2., 3.]);
+    assert!(orthonormalize(&[&a, &c, &d], true, TOL).is_err());
+    assert!(orthonormalize(&[&a, &c], true, TOL).is_ok());
+
+    // relative noise far below the tolerance still counts as dependent
+    let e = V!([1., 2., 3. + 3e-14]);
+    assert!(orthonormalize(&[&a, &e], false, TOL).is_err());
+    assert!(orthonormalize(&[&a, &e], false, 0.).is_ok());
+
+    // exact types detect it exactly, as long as the norms are rational
+    let q = |n: i64| Rational::from(n);
+    let (a, b) = (V!([q(3), q(4)]), V!([q(6), q(8)]));
+    assert!(orthonormalize(&[&a, &b], false, q(0)).is_err());
+    let r =
+        orthonormalize(&[&V!([q(3), q(4)]), &V!([q(1), q(0)])], false, q(0));
+    let r = r.unwrap();
+    assert_eq!(r[0][0], Rational::new(3, 5));
+    assert_eq!(r[1][0], Rational::new(4, 5));
+    assert_eq!(r[1][1], Rational::new(-3, 5));
+}
+
+#[test]
+fn test_reorthogonalize() {
+    // Lauchli vectors lose orthogonality to cancellation
+    let e = 1e-7;
+    let vs = [
+        V!([1., e, 0., 0.]),
+        V!([1., 0., e, 0.]),
+        V!([1., 0., 0., e]),
+    ];
+    let refs: Vec<&Vector<f64>> = vs.iter().collect();
+    let once = loss(&orthonormalize(&refs, false, TOL).unwrap());
+    let twice = loss(&orthonormalize(&refs, true, TOL).unwrap());
+    assert!(twice < 1e-15, "{}", twice);
+    assert!(twice <= once, "{} {}", once, twice);
+
+    // the Hilbert matrix is much worse
+    let n = 8;
+    let h: Vec<Vector<f64>> = (0..n)
+        .map(|i| {
+            V!((0..n).map(|j| 1. / (i + j + 1) as f64).collect::<Vec<_>>())
+        })
+        .collect();
+    let refs: Vec<&Vector<f64>> = h.iter().collect();
+    let once = loss(&orthonormalize(&refs, false, TOL).unwrap());
+    let twice = loss(&orthonormalize(&refs, true, TOL).unwrap());
+    assert!(once > 1e-10, "{}", once);
+    assert!(twice < 1e-14, "{}", twice);
+}
+
+#[test]
+fn test_orthonormalize_complex() {
+    let a = V!([C!(1., 0.), C!(0., 1.), C!(0., 0.)]);
+    let b = V!([C!(1., 1.), C!(0., 0.), C!(1., 0.)]);
+    let c = V!([C!(0., 0.), C!(2., -1.), C!(0., 3.)]);
+    let q = orthonormalize(&[&a, &b, &c], true, TOL).unwrap();
+    assert!(loss(&q) < 1e-15);
+
+    // a complex multiple is still dependent
+    let d = a.clone() * C!(2., -3.);
+    assert!(orthonormalize(&[&a, &d], false, TOL).is_err());
+
+    let one: Complex = C!(1., 0.);
+    assert!(approx_eq!(q[0].dot(&a).real(), a.norm()));
+    assert!(approx_eq!((q[0].dot(&q[0]) - one).abs(), 0.));
+}
+
+#[test]
+fn test_orthonormalize_rows() {
+    let m = M!([[2., 1., 0.], [1., 3., 1.], [0., 1., 4.]]);
+    let q = m.orthonormalize_rows(true, TOL).unwrap();
+    let id = q.mul_mat(&q.transpose());
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!(approx_eq!(id[(i, j)], if i == j { 1. } else { 0. }));
+        }
+    }
+
+    // A = L Q with L lower triangular, so Q A^T is upper triangular
+    let r = q.mul_mat(&m.transpose());
+    assert!(approx_eq!(r[(1, 0)], 0.) && approx_eq!(r[(2, 0)], 0.));
+    assert!(approx_eq!(r[(2, 1)], 0.));
+
+    let c = M!([[C!(1., 0.), C!(0., 1.)], [C!(2., 0.), C!(1., 1.)]]);
+    let q = c.orthonormalize_rows(false, TOL).unwrap();
+    let id = q.mul_mat(&q.conjugate_transpose());
+    assert!(approx_eq!((id[(0, 1)]).abs(), 0.));
+    assert!(approx_eq!(id[(1, 1)].real(), 1.));
+
+    let s = Matrix::new(2, 2, vec![1., 2., 2., 4.]);
+    assert!(s.orthonormalize_rows(true, TOL).is_err());
+}
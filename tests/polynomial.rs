@@ -0,0 +1,196 @@
+use matrix::{
+    approx_eq, Complex, ComplexField, Matrix, Polynomial, Rational64, Semiring,
+    Transpose, M,
+};
+
+type Q = Rational64;
+
+fn q(coeffs: &[i64]) -> Polynomial<Q> {
+    Polynomial::new(coeffs.iter().map(|&c| Q::from(c)).collect())
+}
+
+fn p(coeffs: &[f64]) -> Polynomial<f64> {
+    Polynomial::new(coeffs.to_vec())
+}
+
+// (x - r0)(x - r1)...
+fn from_roots(roots: &[f64]) -> Polynomial<f64> {
+    roots
+        .iter()
+        .fold(Polynomial::one(), |a, &r| a * p(&[-r, 1.]))
+}
+
+// roots sorted by real then imaginary part
+fn sorted(mut v: Vec<Complex>) -> Vec<Complex> {
+    v.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    v
+}
+
+#[test]
+fn test_polynomial_arithmetic() {
+    let a = q(&[1, 2, 3]);
+    let b = q(&[-1, 0, 0, 1]);
+    assert_eq!(a.clone() + b.clone(), q(&[0, 2, 3, 1]));
+    assert_eq!(a.clone() - a.clone(), Polynomial::default());
+    assert_eq!(a.clone() * b.clone(), q(&[-1, -2, -3, 1, 2, 3]));
+    assert_eq!(-b.clone(), q(&[1, 0, 0, -1]));
+    assert_eq!((a.clone() * b.clone()).degree(), Some(5));
+    assert_eq!(Polynomial::<Q>::default().degree(), None);
+    assert_eq!(q(&[1, 2, 0, 0]).degree(), Some(1));
+
+    assert_eq!(a.eval(Q::from(2)), Q::from(17));
+    assert_eq!(b.derivative(), q(&[0, 0, 3]));
+    assert_eq!(q(&[5]).derivative(), Polynomial::default());
+    assert_eq!(
+        Polynomial::x() * Polynomial::x(),
+        Polynomial::monomial(Q::one(), 2)
+    );
+    assert_eq!(format!("{:?}", p(&[1., 0., -2.])), "1.0 + 0.0x + -2.0x^2");
+
+    // integer coefficients only need a ring
+    let i = Polynomial::new(vec![1i64, 1]);
+    assert_eq!((i.clone() * i).coeffs(), &[1, 2, 1]);
+}
+
+#[test]
+fn test_polynomial_division() {
+    let a = q(&[-1, 0, 0, 1]);
+    let d = q(&[1, 1]);
+    let (quo, rem) = a.div_rem(&d);
+    assert_eq!(quo, q(&[1, -1, 1]));
+    assert_eq!(rem, q(&[-2]));
+    assert_eq!(quo * d.clone() + rem, a);
+
+    // (x - 1)(x - 2) and (x - 1)(x + 3)
+    let u = q(&[2, -3, 1]);
+    let v = q(&[-3, 2, 1]);
+    assert_eq!(u.gcd(&v), q(&[-1, 1]));
+    assert_eq!(u.gcd(&q(&[7])), q(&[1]));
+    assert_eq!((u.clone() * v.clone()) / v.clone(), u);
+    assert_eq!(u.clone() % v.clone(), q(&[5, -5]));
+
+    let g = from_roots(&[0.5, -2., 3.]).gcd(&from_roots(&[3., 0.5, 7.]));
+    let expected = from_roots(&[0.5, 3.]);
+    assert!(g
+        .coeffs()
+        .iter()
+        .zip(expected.coeffs())
+        .all(|(a, b)| approx_eq!(*a, *b)));
+}
+
+#[test]
+#[should_panic(expected = "division by zero polynomial")]
+fn test_polynomial_division_by_zero() {
+    let _ = q(&[1, 2]) / Polynomial::default();
+}
+
+#[test]
+fn test_polynomial_roots() {
+    let r = sorted(p(&[1., 0., 1.]).roots());
+    assert!(approx_eq!(r[0].x, 0.) && approx_eq!(r[0].y, -1.));
+    assert!(approx_eq!(r[1].x, 0.) && approx_eq!(r[1].y, 1.));
+
+    let r = sorted(p(&[0., -1., 0., 1.]).roots());
+    assert_eq!(r.len(), 3);
+    assert!([-1., 0., 1.]
+        .iter()
+        .zip(&r)
+        .all(|(e, r)| approx_eq!(r.x, *e) && approx_eq!(r.y, 0.)));
+
+    // Wilkinson's polynomial is badly conditioned even at degree 10
+    let w: Vec<f64> = (1..=10).map(|i| i as f64).collect();
+    let r = sorted(from_roots(&w).roots());
+    assert!(w.iter().zip(&r).all(|(e, r)| (r.x - e).abs() < 1e-6));
+
+    // a double root is only found to about half the precision
+    let r = from_roots(&[1., 1., -3.]).roots();
+    assert_eq!(r.iter().filter(|r| (r.x - 1.).abs() < 1e-6).count(), 2);
+
+    // complex coefficients, (x - i)(x - 2 + i)
+    let (i, one) = (Complex::i(), Complex::one());
+    let c = Polynomial::new(vec![-i, one])
+        * Polynomial::new(vec![i - one - one, one]);
+    let r = sorted(c.roots());
+    assert!(approx_eq!(r[0].x, 0.) && approx_eq!(r[0].y, 1.));
+    assert!(approx_eq!(r[1].x, 2.) && approx_eq!(r[1].y, -1.));
+
+    // every root of a random polynomial is a root
+    let coeffs: Vec<f64> =
+        (0..16).map(|k| ((k * 37 % 11) as f64) - 5.).collect();
+    let rand = Polynomial::new(
+        coeffs.iter().map(|&c| Complex::from([c, 0.])).collect(),
+    );
+    let r = rand.roots();
+    assert_eq!(r.len(), rand.degree().unwrap());
+    for z in r {
+        let scale: f64 = coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c.abs() * z.abs().powi(k as i32))
+            .sum();
+        assert!(rand.eval(z).abs() < 1e-10 * scale);
+    }
+    assert!(p(&[3.]).roots().is_empty());
+
+    // tiny coefficients are kept, only exact zeros are trimmed
+    let t = p(&[1., 1., 1e-11]);
+    assert_eq!(t.degree(), Some(2));
+    let r = sorted(t.roots());
+    assert!(approx_eq!(r[0].x / -1e11, 1., 1e-9));
+    assert!(approx_eq!(r[1].x, -1., 1e-4));
+    let r = sorted(p(&[-1e-12, 0., 1e-12]).roots());
+    assert!(approx_eq!(r[0], Complex::from([-1., 0.])));
+    assert!(approx_eq!(r[1], Complex::from([1., 0.])));
+    assert_ne!(p(&[1e-20]), Polynomial::default());
+}
+
+#[test]
+fn test_polynomial_matrix() {
+    // det(λI - A) formed symbolically
+    let a = M!([[2, 1, 0], [1, 3, 1], [0, 1, 4]]).map(Q::from);
+    let lambda_i_minus_a = Matrix::new(
+        3,
+        3,
+        (0..9)
+            .map(|k| {
+                let (i, j) = (k / 3, k % 3);
+                let x = if i == j {
+                    Polynomial::x()
+                } else {
+                    Polynomial::default()
+                };
+                x - Polynomial::constant(a[(i, j)])
+            })
+            .collect(),
+    );
+    let char_poly = lambda_i_minus_a.determinant();
+    assert_eq!(char_poly, q(&[-18, 24, -9, 1]));
+    assert_eq!(char_poly.eval(Q::default()), -a.determinant());
+
+    // the same over floats goes through cofactor expansion
+    let af = a.map(|v| v.to_f64());
+    let det = Matrix::new(
+        3,
+        3,
+        (0..9)
+            .map(|k| {
+                let (i, j) = (k / 3, k % 3);
+                let x = if i == j { p(&[0., 1.]) } else { p(&[]) };
+                x - Polynomial::constant(af[(i, j)])
+            })
+            .collect(),
+    )
+    .determinant();
+    assert!(det
+        .coeffs()
+        .iter()
+        .zip([-18., 24., -9., 1.])
+        .all(|(a, b)| approx_eq!(*a, b)));
+
+    let m = M!([[q(&[0, 1]), q(&[1])], [q(&[2]), q(&[1, 1])]]);
+    assert_eq!(m.trace(), Some(q(&[1, 2])));
+    assert_eq!(m.transpose()[(0, 1)], q(&[2]));
+    let sq = m.mul_mat(&m);
+    assert_eq!(sq[(0, 0)], q(&[2, 0, 1]));
+    assert_eq!(sq[(1, 1)], q(&[3, 2, 1]));
+}
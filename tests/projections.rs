@@ -16,7 +16,8 @@ fn test_projection() {
     let mut file =
         File::create("matrix_display/proj").expect("Failed to create newline");
 
-    for row in mat._d.chunks(mat.cols) {
+    for row in mat.rows() {
+        let row: Vec<_> = row.collect();
         for i in 0..row.len() {
             write!(file, "{}", row[i]).expect("Failed to write newline");
             if i != row.len() - 1 {
@@ -0,0 +1,175 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use matrix::{approx_eq, lerp, vec3::rotate3z, Matrix, Quaternion, Vector, V};
+
+type Q = Quaternion;
+
+fn close(u: &Vector<f64>, v: &[f64]) -> bool {
+    u.iter().zip(v).all(|(a, b)| (a - b).abs() < 1e-12)
+}
+
+fn same(p: Q, q: Q) -> bool {
+    let d = (p - q).norm().min((p + q).norm());
+    d < 1e-12
+}
+
+fn axis(x: f64, y: f64, z: f64) -> Vector<f64> {
+    V!([x, y, z])
+}
+
+#[test]
+fn test_quaternion_algebra() {
+    let (i, j, k) = (
+        Q::new(0., 1., 0., 0.),
+        Q::new(0., 0., 1., 0.),
+        Q::new(0., 0., 0., 1.),
+    );
+    let minus_one = Q::new(-1., 0., 0., 0.);
+    assert_eq!(i * i, minus_one);
+    assert_eq!(j * j, minus_one);
+    assert_eq!(i * j * k, minus_one);
+    assert_eq!(i * j, k);
+    assert_eq!(j * i, -k);
+    assert_eq!(k * i, j);
+
+    let p = Q::new(1., 2., -3., 0.5);
+    let q = Q::from([0.5, -1., 2., 4.]);
+    assert_eq!((p * q).conj(), q.conj() * p.conj());
+    assert!(approx_eq!((p * q).norm(), p.norm() * q.norm()));
+    assert!(same(p * p.inverse(), Q::identity()));
+    assert!(approx_eq!(p.normalize().norm(), 1.));
+
+    // a short quaternion is still invertible
+    let s = Q::new(1e-6, 0., 0., 0.);
+    assert!(approx_eq!(s.inverse().w, 1e6));
+    assert_eq!(s.normalize(), Q::identity());
+    assert_eq!(p + q - q, p);
+    assert_eq!(p * 2., p + p);
+    assert_eq!(format!("{}", i), "(0 + 1i + 0j + 0k)");
+}
+
+#[test]
+fn test_quaternion_rotation() {
+    let q = Q::from_axis_angle(&axis(0., 0., 2.), FRAC_PI_2);
+    assert!(close(&q.rotate(&axis(1., 0., 0.)), &[0., 1., 0.]));
+    assert!(close(&q.rotate(&axis(0., 0., 3.)), &[0., 0., 3.]));
+
+    // agrees with the principal axis rotations
+    let v = axis(1., -2., 0.5);
+    let e = rotate3z(&v, 0.7);
+    let r = Q::from_axis_angle(&axis(0., 0., 1.), 0.7).rotate(&v);
+    assert!(close(&r, &[e[0], e[1], e[2]]));
+
+    // composition applies the right factor first
+    let a = Q::from_axis_angle(&axis(1., 0., 0.), 0.3);
+    let b = Q::from_axis_angle(&axis(0., 1., 1.), -1.1);
+    let two = b.rotate(&a.rotate(&v));
+    assert!(close(&(b * a).rotate(&v), &[two[0], two[1], two[2]]));
+
+    // the rotation preserves length and the conjugate undoes it
+    let w = b.rotate(&v);
+    assert!(approx_eq!(w.norm(), v.norm()));
+    assert!(close(&b.conj().rotate(&w), &[1., -2., 0.5]));
+
+    let (ax, angle) = b.to_axis_angle();
+    let s = 0.5f64.sqrt();
+    assert!(close(&ax, &[0., -s, -s]) && approx_eq!(angle, 1.1));
+    let (ax, angle) = Q::identity().to_axis_angle();
+    assert!(close(&ax, &[1., 0., 0.]) && angle == 0.);
+}
+
+#[test]
+fn test_quaternion_matrix() {
+    let q = Q::new(0.3, -0.5, 0.2, 0.8).normalize();
+    let m = q.to_matrix3();
+    let v = axis(0.4, 1.5, -2.);
+    let mv = &m * &v;
+    let qv = q.rotate(&v);
+    assert!(close(&mv, &[qv[0], qv[1], qv[2]]));
+    assert!(same(Q::from_rotation_matrix(&m), q));
+
+    let h = q.to_matrix4();
+    assert_eq!(h.shape(), (4, 4));
+    assert_eq!(h[(3, 3)], 1.);
+    assert_eq!(h[(0, 3)], 0.);
+    assert!(same(Q::from_rotation_matrix(&h), q));
+
+    // each branch of the conversion, including half turns
+    for (x, y, z, angle) in [
+        (1., 0., 0., PI),
+        (0., 1., 0., PI),
+        (0., 0., 1., PI),
+        (1., 1., 0., 3.),
+        (0.2, -1., 0.3, 2.5),
+        (0., 0., 1., 0.),
+    ] {
+        let q = Q::from_axis_angle(&axis(x, y, z), angle);
+        assert!(same(Q::from_rotation_matrix(&q.to_matrix3()), q));
+    }
+}
+
+#[test]
+#[should_panic(expected = "matrix must be 3x3 or 4x4")]
+fn test_quaternion_bad_matrix() {
+    let _ = Q::from_rotation_matrix(&Matrix::new(2, 2, vec![1., 0., 0., 1.]));
+}
+
+#[test]
+fn test_quaternion_euler() {
+    let (roll, pitch, yaw) = (0.3, -0.7, 2.1);
+    let q = Q::from_euler(roll, pitch, yaw);
+    let z = Q::from_axis_angle(&axis(0., 0., 1.), yaw);
+    let y = Q::from_axis_angle(&axis(0., 1., 0.), pitch);
+    let x = Q::from_axis_angle(&axis(1., 0., 0.), roll);
+    assert!(same(q, z * y * x));
+
+    let (r, p, w) = q.to_euler();
+    assert!(approx_eq!(r, roll) && approx_eq!(p, pitch) && approx_eq!(w, yaw));
+
+    // at gimbal lock only the difference of roll and yaw is observable
+    let q = Q::from_euler(0.4, FRAC_PI_2, 0.9);
+    let (r, p, w) = q.to_euler();
+    assert!(r == 0. && approx_eq!(p, FRAC_PI_2) && approx_eq!(w, 0.5));
+    assert!(same(Q::from_euler(r, p, w), q));
+
+    let q = Q::from_euler(0.4, -FRAC_PI_2, 0.9);
+    let (r, p, w) = q.to_euler();
+    assert!(r == 0. && approx_eq!(p, -FRAC_PI_2) && approx_eq!(w, 1.3));
+    assert!(same(Q::from_euler(r, p, w), q));
+
+    // close to the lock but not in it all three angles come back
+    let q = Q::from_euler(0.3, FRAC_PI_2 - 1e-3, 0.2);
+    let (r, p, w) = q.to_euler();
+    assert!(approx_eq!(r, 0.3) && approx_eq!(w, 0.2));
+    assert!(approx_eq!(p, FRAC_PI_2 - 1e-3));
+    assert!(same(Q::from_euler(r, p, w), q));
+}
+
+#[test]
+fn test_quaternion_interpolation() {
+    let a = Q::identity();
+    let b = Q::from_axis_angle(&axis(0., 0., 1.), 2.);
+    let mid = Q::slerp(a, b, 0.5);
+    assert!(same(mid, Q::from_axis_angle(&axis(0., 0., 1.), 1.)));
+    let quarter = Q::slerp(a, b, 0.25);
+    assert!(same(quarter, Q::from_axis_angle(&axis(0., 0., 1.), 0.5)));
+
+    // the Lerp trait goes through slerp
+    assert!(same(lerp(a, b, 0.5), mid));
+    assert_eq!(lerp(a, b, 0.), a);
+    assert_eq!(lerp(a, b, 1.), b);
+
+    // the shorter arc is taken even when the signs disagree
+    assert!(same(Q::slerp(a, -b, 0.5), mid));
+
+    let n = Q::nlerp(a, b, 0.5);
+    assert!(approx_eq!(n.norm(), 1.));
+    assert!(same(n, mid));
+    let n = Q::nlerp(a, b, 0.25);
+    let (_, angle) = n.to_axis_angle();
+    assert!(angle > 0.4 && angle < 0.5, "{}", angle);
+
+    // nearly equal inputs stay finite
+    let c = Q::from_axis_angle(&axis(1., 0., 0.), 1e-9);
+    assert!(approx_eq!(Q::slerp(a, c, 0.5).norm(), 1.));
+}
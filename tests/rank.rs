@@ -81,3 +81,11 @@ fn test_rank_complex() {
     let a = u.rank();
     assert_eq!(a, 2);
 }
+
+#[test]
+fn test_row_space() {
+    let u = M!([[1., 2., 0., 0.], [2., 4., 0., 0.], [-1., 2., 1., 1.]]);
+    let r = u.row_space();
+    assert_eq!(r.rank(), 2);
+    assert!(r.iter().eq([1., 0., -0.5, -0.5, 0., 1., 0.25, 0.25].iter()));
+}
@@ -0,0 +1,149 @@
+use matrix::{
+    scalar::{Lerp, Sqrt},
+    ComplexField, Dot, Field, Matrix, Rational, Rational128, Vector, M, V,
+};
+
+fn q(num: i64, den: i64) -> Rational {
+    Rational::new(num, den)
+}
+
+fn hilbert(n: usize) -> Matrix<Rational> {
+    Matrix::new(
+        n,
+        n,
+        (0..n * n)
+            .map(|k| q(1, (k / n + k % n + 1) as i64))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_rational_normalized() {
+    assert_eq!(q(2, 4), q(1, 2));
+    assert_eq!(q(3, -6), q(-1, 2));
+    assert_eq!(q(0, -5), Rational::default());
+    assert_eq!(*q(-4, -8).numer(), 1);
+    assert_eq!(*q(-4, -8).denom(), 2);
+    assert_eq!(format!("{:?} {}", q(6, 3), q(-2, 6)), "2 -1/3");
+}
+
+#[test]
+fn test_rational_arithmetic() {
+    assert_eq!(q(1, 2) + q(1, 3), q(5, 6));
+    assert_eq!(q(1, 2) - q(1, 3), q(1, 6));
+    assert_eq!(q(2, 3) * q(9, 4), q(3, 2));
+    assert_eq!(q(2, 3) / q(4, 9), q(3, 2));
+    assert_eq!(-q(2, 3), q(-2, 3));
+    assert_eq!(q(2, 3).inv(), q(3, 2));
+    assert_eq!(q(2, 3).powf(q(-3, 1)), q(27, 8));
+    assert_eq!(q(9, 16).sqrt(), q(3, 4));
+    assert_eq!(q(3, 1).hypot(q(4, 1)), q(5, 1));
+    assert_eq!(Rational::lerp(q(0, 1), q(1, 1), 0.25), q(1, 4));
+}
+
+#[test]
+fn test_rational_ordering() {
+    assert!(q(1, 3) < q(1, 2));
+    assert!(q(-1, 2) < q(-1, 3));
+    assert!(q(i64::MAX - 1, i64::MAX) < q(i64::MAX - 2, i64::MAX - 1).inv());
+    assert!(q(i64::MAX - 2, i64::MAX - 1) < q(i64::MAX - 1, i64::MAX));
+    assert_eq!(
+        q(7, 5).partial_cmp(&q(14, 10)),
+        Some(std::cmp::Ordering::Equal)
+    );
+}
+
+#[test]
+fn test_rational_overflow_detection() {
+    let big = q(i64::MAX, 1);
+    assert_eq!(big.checked_add(q(1, 1)), None);
+    assert_eq!(big.checked_mul(q(2, 1)), None);
+    assert_eq!(q(i64::MIN, 1).checked_neg(), None);
+    assert_eq!(q(1, i64::MAX).checked_mul(q(1, 2)), None);
+    assert_eq!(big.checked_add(q(-1, 1)), Some(q(i64::MAX - 1, 1)));
+}
+
+#[test]
+#[should_panic(expected = "rational overflow")]
+fn test_rational_overflow_panics() {
+    let _ = q(i64::MAX, 1) + q(1, 1);
+}
+
+#[test]
+#[should_panic(expected = "square root of negative value")]
+fn test_rational_sqrt_negative() {
+    let _ = q(-2, 1).sqrt();
+}
+
+#[test]
+fn test_rational_approximation() {
+    assert_eq!(Rational::from_f64(0.75), q(3, 4));
+    assert_eq!(Rational::from_f64(-2.5), q(-5, 2));
+    assert!((q(1, 1).exp().to_f64() - std::f64::consts::E).abs() < 1e-12);
+    assert!((q(2, 1).sqrt().to_f64() - 2_f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_rational_rank_exact() {
+    // the float elimination loses a pivot below `EPSILON`
+    let f = Matrix::new(
+        10,
+        10,
+        (0..100)
+            .map(|k| 1. / ((k / 10 + k % 10 + 1) as f64))
+            .collect(),
+    );
+    assert_eq!(f.rank(), 9);
+    assert_eq!(hilbert(10).rank(), 10);
+
+    let m = M!([
+        [q(1, 3), q(2, 3), q(1, 1)],
+        [q(1, 7), q(2, 7), q(3, 7)],
+        [q(1, 2), q(1, 5), q(0, 1)],
+    ]);
+    assert_eq!(m.rank(), 2);
+}
+
+#[test]
+fn test_rational_row_echelon_exact() {
+    let m = M!([[q(1, 3), q(1, 2), q(1, 1)], [q(2, 3), q(1, 1), q(1, 5)]]);
+    let r = m.row_echelon();
+    assert!(r.iter().eq(&[
+        q(1, 1),
+        q(3, 2),
+        q(0, 1),
+        q(0, 1),
+        q(0, 1),
+        q(1, 1)
+    ]));
+}
+
+#[test]
+fn test_rational_determinant_and_inverse_exact() {
+    let h = hilbert(4);
+    assert_eq!(h.determinant(), q(1, 6048000));
+
+    let inv = h.inverse().unwrap();
+    let expected = [
+        16, -120, 240, -140, -120, 1200, -2700, 1680, 240, -2700, 6480, -4200,
+        -140, 1680, -4200, 2800,
+    ];
+    assert!(inv.iter().eq(expected.map(|v| q(v, 1)).iter()));
+
+    let id = h.mul_mat(&inv);
+    assert!(id
+        .iter()
+        .enumerate()
+        .all(|(k, v)| *v == if k % 5 == 0 { q(1, 1) } else { q(0, 1) }));
+}
+
+#[test]
+fn test_rational128() {
+    let u: Vector<Rational128> = V!([
+        Rational::new(1, 3),
+        Rational::new(1, 6),
+        Rational::new(-1, 2)
+    ]);
+    assert_eq!(u.dot(&u), Rational::new(7, 18));
+    assert_eq!(u.norm_1(), Rational::new(1, 1));
+}
@@ -0,0 +1,141 @@
+use std::f64::consts::PI;
+
+use matrix::{
+    approx_eq,
+    vec3::{
+        axis_angle3, is_rotation3, relative_rotation3, reorthonormalize3,
+        rotate3, rotate3x, rotate3z, rotation3,
+    },
+    Matrix, Quaternion, Vector, M, V,
+};
+
+fn close(u: &Vector<f64>, v: &Vector<f64>) -> bool {
+    u.iter().zip(v.iter()).all(|(a, b)| (a - b).abs() < 1e-12)
+}
+
+fn max_diff(a: &Matrix<f64>, b: &Matrix<f64>) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).abs())
+        .fold(0., f64::max)
+}
+
+#[test]
+fn test_rodrigues() {
+    let v = V!([1., -2., 0.5]);
+    assert!(close(
+        &rotate3(&v, &V!([3., 0., 0.]), 0.4),
+        &rotate3x(&v, 0.4)
+    ));
+    assert!(close(
+        &rotate3(&v, &V!([0., 0., 1.]), -1.3),
+        &rotate3z(&v, -1.3)
+    ));
+
+    let axis = V!([1., 1., 1.]);
+    let r = rotate3(&V!([1., 0., 0.]), &axis, 2. * PI / 3.);
+    assert!(close(&r, &V!([0., 1., 0.])));
+    assert!(close(&rotate3(&axis, &axis, 1.), &axis));
+
+    // the matrix form and the quaternion agree with the vector form
+    let axis = V!([0.3, -1., 2.]);
+    let e = rotate3(&v, &axis, 0.8);
+    assert!(close(&(&rotation3(&axis, 0.8) * &v), &e));
+    let q = Quaternion::from_axis_angle(&axis, 0.8);
+    assert!(close(&q.rotate(&v), &e));
+    assert!(max_diff(&rotation3(&axis, 0.8), &q.to_matrix3()) < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "vector must be non-zero")]
+fn test_rodrigues_zero_axis() {
+    let _ = rotate3(&V!([1., 0., 0.]), &V!([0., 0., 0.]), 1.);
+}
+
+#[test]
+fn test_is_rotation() {
+    assert!(is_rotation3(&rotation3(&V!([1., 2., 3.]), 0.9), 1e-12));
+    let mirror = M!([[1., 0., 0.], [0., 1., 0.], [0., 0., -1.]]);
+    assert!(!is_rotation3(&mirror, 1e-12));
+    let scaled = rotation3(&V!([1., 0., 0.]), 0.2) * 1.001;
+    assert!(!is_rotation3(&scaled, 1e-6));
+    assert!(is_rotation3(&scaled, 1e-2));
+}
+
+#[test]
+fn test_reorthonormalize() {
+    // integrating a constant angular rate with a noisy update drifts away
+    // from the rotation group
+    let step = rotation3(&V!([0.2, -0.5, 1.]), 1e-3);
+    let mut r = Matrix::new(3, 3, vec![1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+    let mut state = 7u64;
+    for _ in 0..5000 {
+        r = r.mul_mat(&step);
+        for i in 0..3 {
+            for j in 0..3 {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                r[(i, j)] +=
+                    ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 1e-6;
+            }
+        }
+    }
+    assert!(!is_rotation3(&r, 1e-6));
+    let fixed = reorthonormalize3(&r);
+    assert!(is_rotation3(&fixed, 1e-14));
+    assert!(max_diff(&fixed, &r) < 1e-3);
+
+    // the polar factor of R (I + S) with S symmetric is R itself
+    let rot = rotation3(&V!([1., -1., 0.5]), 2.);
+    let s = M!([
+        [1e-4, 2e-5, -3e-5],
+        [2e-5, -5e-5, 1e-5],
+        [-3e-5, 1e-5, 2e-4]
+    ]);
+    let id = Matrix::new(3, 3, vec![1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+    let drifted = rot.mul_mat(&(id + s));
+    assert!(max_diff(&reorthonormalize3(&drifted), &rot) < 1e-14);
+    assert!(max_diff(&reorthonormalize3(&rot), &rot) < 1e-15);
+}
+
+#[test]
+fn test_axis_angle() {
+    for (axis, angle) in [
+        (V!([0., 0., 1.]), 0.5),
+        (V!([1., 2., -2.]), 2.9),
+        (V!([0., 1., 0.]), PI),
+        (V!([1., 1., 0.]), PI - 1e-9),
+        (V!([-3., 0.5, 1.]), 1e-6),
+    ] {
+        let n = axis.norm();
+        let (a, t) = axis_angle3(&rotation3(&axis, angle));
+        assert!(approx_eq!(t, angle), "{} {}", t, angle);
+        let unit = axis * (1. / n);
+        assert!(close(&a, &unit) || (angle == PI && close(&(a * -1.), &unit)));
+    }
+
+    // past a half turn the axis flips
+    let (a, t) = axis_angle3(&rotation3(&V!([0., 0., 1.]), 4.));
+    assert!(approx_eq!(t, 2. * PI - 4.));
+    assert!(close(&a, &V!([0., 0., -1.])));
+
+    let id = Matrix::new(3, 3, vec![1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+    let (a, t) = axis_angle3(&id);
+    assert!(t == 0. && close(&a, &V!([1., 0., 0.])));
+}
+
+#[test]
+fn test_relative_rotation() {
+    let a = rotation3(&V!([1., 0., 0.]), 0.3);
+    let b = rotation3(&V!([0.5, 1., -1.]), 1.7);
+    let r = relative_rotation3(&a, &b);
+    assert!(is_rotation3(&r, 1e-12));
+    assert!(max_diff(&r.mul_mat(&a), &b) < 1e-14);
+
+    // about a shared axis the relative angle is the difference
+    let z = V!([0., 0., 1.]);
+    let r = relative_rotation3(&rotation3(&z, 0.4), &rotation3(&z, 1.5));
+    let (axis, angle) = axis_angle3(&r);
+    assert!(close(&axis, &z) && approx_eq!(angle, 1.1));
+}
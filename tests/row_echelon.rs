@@ -1,20 +1,20 @@
-use matrix::{approx_eq, Complex, Matrix, Scalar, C, M};
+use matrix::{approx_eq, Complex, Matrix, C, M};
 
 #[test]
 fn test_row_echelon() {
     let u = M!([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
     let a = u.row_echelon();
 
-    assert!(a._d.eq(&[1., 0., 0., 0., 1., 0., 0., 0., 1.]));
+    assert!(a.iter().eq(&[1., 0., 0., 0., 1., 0., 0., 0., 1.]));
 
     let u = M!([[1., 2.], [3., 4.]]);
     let a = u.row_echelon();
-    assert!(a._d.eq(&[1., 0., 0., 1.]));
+    assert!(a.iter().eq(&[1., 0., 0., 1.]));
 
     let u = M!([[1., 2.], [2., 4.]]);
     let a = u.row_echelon();
 
-    assert!(a._d.eq(&[1., 2., 0., 0.]));
+    assert!(a.iter().eq(&[1., 2., 0., 0.]));
 
     let u = M!([
         [8., 5., -2., 4., 28.],
@@ -24,7 +24,6 @@ fn test_row_echelon() {
     let a = u.row_echelon();
 
     assert!(a
-        ._d
         .iter()
         .zip([
             1.0_f64,
@@ -51,7 +50,6 @@ fn test_row_echelon_complex() {
     let u = M!([[C!(1., -1.), C!(2., 3.)], [C!(4., 5.), C!(6., 7.)]]);
     let a = u.row_echelon();
     assert!(a
-        ._d
         .iter()
         .zip(&[C!(1., 0.), C!(0., 0.), C!(0., 0.), C!(1., 0.)])
         .all(|(x, y)| approx_eq!(*x, *y)));
@@ -59,7 +57,6 @@ fn test_row_echelon_complex() {
     let u = M!([[C!(1., 2.), C!(3., -12.)], [C!(2., 4.), C!(6., -24.)]]);
     let a = u.row_echelon();
     assert!(a
-        ._d
         .iter()
         .zip(&[C!(1., 0.), C!(-4.2, -3.6), C!(0., 0.), C!(0., 0.)])
         .all(|(x, y)| approx_eq!(*x, *y)));
@@ -71,7 +68,6 @@ fn test_row_echelon_complex() {
     ]);
     let a = u.row_echelon();
     assert!(a
-        ._d
         .iter()
         .zip(&[
             C!(1., 0.),
@@ -5,7 +5,7 @@ fn test_vector_scl() {
     let mut u = V!([2., 3.]);
     let v = 2.;
     u.scl(v);
-    assert!(u._d.iter().eq(&[4., 6.]));
+    assert!(u.iter().eq(&[4., 6.]));
 }
 
 #[test]
@@ -13,7 +13,7 @@ fn test_matrix_scl() {
     let mut u = M!([[1., 2.], [3., 4.]]);
     let v = 2.;
     u.scl(v);
-    assert!(u._d.iter().eq(&[2., 4., 6., 8.]));
+    assert!(u.iter().eq(&[2., 4., 6., 8.]));
 }
 
 #[test]
@@ -38,17 +38,17 @@ fn test_scl_complex_vector() {
     let mut u = V!([C!(1., 2.), C!(-1., -3.)]);
     let v = C!(2., 3.);
     u.scl(v);
-    assert!(u._d.iter().eq(&[C!(-4., 7.), C!(7., -9.)]));
+    assert!(u.iter().eq(&[C!(-4., 7.), C!(7., -9.)]));
 
     u = V!([C!(1., 0.), C!(2., 0.)]);
     let v = C!(2., 3.);
     u.scl(v);
-    assert!(u._d.iter().eq(&[C!(2., 3.), C!(4., 6.)]));
+    assert!(u.iter().eq(&[C!(2., 3.), C!(4., 6.)]));
 
     u = V!([C!(1., 0.), C!(2., 0.), C!(3., 0.)]);
     let v = C!(2., 3.);
     u.scl(v);
-    assert!(u._d.iter().eq(&[C!(2., 3.), C!(4., 6.), C!(6., 9.)]));
+    assert!(u.iter().eq(&[C!(2., 3.), C!(4., 6.), C!(6., 9.)]));
 }
 
 #[test]
@@ -56,7 +56,7 @@ fn test_scl_complex_matrix() {
     let mut u = M!([[C!(1., 2.), C!(-1., -3.)], [C!(2., 0.), C!(3., 0.)]]);
     let v = C!(2., 3.);
     u.scl(v);
-    assert!(u._d.iter().eq(&[
+    assert!(u.iter().eq(&[
         C!(-4., 7.),
         C!(7., -9.),
         C!(4., 6.),
@@ -66,7 +66,7 @@ fn test_scl_complex_matrix() {
     u = M!([[C!(1., 0.), C!(2., 0.)], [C!(3., 0.), C!(4., 0.)]]);
     let v = C!(2., 3.);
     u.scl(v);
-    assert!(u._d.iter().eq(&[
+    assert!(u.iter().eq(&[
         C!(2., 3.),
         C!(4., 6.),
         C!(6., 9.),
@@ -79,7 +79,7 @@ fn test_scl_complex_matrix() {
     ]);
     let v = C!(9., 4.);
     u.scl(v);
-    assert!(u._d.iter().eq(&[
+    assert!(u.iter().eq(&[
         C!(9., 4.),
         C!(18., 8.),
         C!(27., 12.),
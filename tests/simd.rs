@@ -0,0 +1,84 @@
+use matrix::{linear_combination, simd, Dot, Vector, V};
+
+fn sample_f64(n: usize, seed: f64) -> Vec<f64> {
+    (0..n).map(|i| ((i as f64 + seed) * 0.7).sin() * 10.).collect()
+}
+
+fn sample_f32(n: usize, seed: f32) -> Vec<f32> {
+    (0..n).map(|i| ((i as f32 + seed) * 0.7).sin() * 10.).collect()
+}
+
+#[test]
+fn test_simd_dot() {
+    for n in [0, 1, 3, 4, 7, 8, 9, 15, 16, 17, 31, 64, 100, 1023] {
+        let u = sample_f64(n, 1.);
+        let v = sample_f64(n, 2.);
+        let mut expected = 0_f64;
+        let mut bound = 0_f64;
+        for i in 0..n {
+            expected = u[i].mul_add(v[i], expected);
+            bound += (u[i] * v[i]).abs();
+        }
+        let tol = n as f64 * f64::EPSILON * bound;
+        assert!((simd::dot_f64(&u, &v) - expected).abs() <= tol);
+
+        let u = sample_f32(n, 1.);
+        let v = sample_f32(n, 2.);
+        let mut expected = 0_f32;
+        let mut bound = 0_f32;
+        for i in 0..n {
+            expected = u[i].mul_add(v[i], expected);
+            bound += (u[i] * v[i]).abs();
+        }
+        let tol = n as f32 * f32::EPSILON * bound;
+        assert!((simd::dot_f32(&u, &v) - expected).abs() <= tol);
+    }
+}
+
+#[test]
+fn test_simd_elementwise() {
+    for n in [0, 1, 5, 8, 13, 33, 257] {
+        let x = sample_f64(n, 3.);
+        let mut y = sample_f64(n, 4.);
+        let expected: Vec<f64> =
+            y.iter().zip(&x).map(|(y, x)| x.mul_add(-1.5, *y)).collect();
+        simd::axpy_f64(&mut y, -1.5, &x);
+        assert_eq!(y, expected);
+
+        let expected: Vec<f64> = y.iter().map(|y| y * 0.25).collect();
+        simd::scale_f64(&mut y, 0.25);
+        assert_eq!(y, expected);
+
+        let expected: Vec<f64> = y.iter().zip(&x).map(|(y, x)| y + x).collect();
+        simd::add_f64(&mut y, &x);
+        assert_eq!(y, expected);
+
+        let x = sample_f32(n, 3.);
+        let mut y = sample_f32(n, 4.);
+        let expected: Vec<f32> =
+            y.iter().zip(&x).map(|(y, x)| x.mul_add(-1.5, *y)).collect();
+        simd::axpy_f32(&mut y, -1.5, &x);
+        assert_eq!(y, expected);
+
+        let expected: Vec<f32> = y.iter().map(|y| y * 0.25).collect();
+        simd::scale_f32(&mut y, 0.25);
+        assert_eq!(y, expected);
+
+        let expected: Vec<f32> = y.iter().zip(&x).map(|(y, x)| y + x).collect();
+        simd::add_f32(&mut y, &x);
+        assert_eq!(y, expected);
+    }
+}
+
+#[test]
+fn test_simd_vector_paths() {
+    let u = V!(sample_f64(37, 5.));
+    let v = V!(sample_f64(37, 6.));
+    let expected: f64 = (0..37).map(|i| u[i] * v[i]).sum();
+    assert!((u.dot(&v) - expected).abs() < 1e-9);
+
+    let a = linear_combination(&[&u, &v], &[2., -3.]);
+    for i in 0..37 {
+        assert_eq!(a[i], v[i].mul_add(-3., u[i] * 2.));
+    }
+}
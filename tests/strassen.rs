@@ -0,0 +1,84 @@
+use matrix::{Complex, Matrix, Scalar, C};
+
+fn sample(rows: usize, cols: usize, seed: f64) -> Matrix<f64> {
+    let mut vec = Vec::with_capacity(rows * cols);
+    for i in 0..rows * cols {
+        vec.push(((i as f64 + seed) * 1.3).sin());
+    }
+    Matrix::new(rows, cols, vec)
+}
+
+fn sample_complex(rows: usize, cols: usize, seed: f64) -> Matrix<Complex> {
+    let mut vec = Vec::with_capacity(rows * cols);
+    for i in 0..rows * cols {
+        let t = (i as f64 + seed) * 1.3;
+        vec.push(C!(t.sin(), t.cos()));
+    }
+    Matrix::new(rows, cols, vec)
+}
+
+fn max_error<K: Scalar<AbsOutput = f64> + Copy>(
+    a: &Matrix<K>,
+    b: &Matrix<K>,
+) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (*x - *y).abs())
+        .fold(0., f64::max)
+}
+
+#[test]
+fn test_strassen_exact_small_integers() {
+    let a = Matrix::from([
+        [1., 2., 3., 4., 5.],
+        [6., 7., 8., 9., 10.],
+        [11., 12., 13., 14., 15.],
+        [16., 17., 18., 19., 20.],
+    ]);
+    let b = Matrix::from([
+        [1., 0., 2.],
+        [0., 1., 3.],
+        [4., 5., 6.],
+        [7., 8., 9.],
+        [-1., -2., -3.],
+    ]);
+    let c = a.mul_mat_strassen(&b, 1);
+    assert_eq!(c.shape(), (4, 3));
+    assert!(c.iter().eq(a.mul_mat(&b).iter()));
+}
+
+#[test]
+fn test_strassen_f64() {
+    for (m, k, n) in [(16, 16, 16), (33, 33, 33), (64, 64, 64), (65, 47, 70)] {
+        let a = sample(m, k, 1.);
+        let b = sample(k, n, 2.);
+        let classical = a.mul_mat(&b);
+        for cutoff in [1, 4, 8, 32] {
+            let fast = a.mul_mat_strassen(&b, cutoff);
+            assert_eq!(fast.shape(), classical.shape());
+            // strassen's error bound grows like k^log2(12) instead of k
+            let tol = (k as f64).powf(12_f64.log2()) * f64::EPSILON;
+            assert!(max_error(&fast, &classical) < tol);
+        }
+    }
+}
+
+#[test]
+fn test_strassen_complex() {
+    for n in [8, 17, 32, 41] {
+        let a = sample_complex(n, n, 3.);
+        let b = sample_complex(n, n, 4.);
+        let classical = a.mul_mat(&b);
+        let fast = a.mul_mat_strassen(&b, 4);
+        let tol = (n as f64).powf(12_f64.log2()) * f64::EPSILON;
+        assert!(max_error(&fast, &classical) < tol);
+    }
+}
+
+#[test]
+#[should_panic(expected = "bad input for matrix and matrix multiplication")]
+fn test_strassen_bad_shape() {
+    let a = sample(4, 3, 1.);
+    let b = sample(4, 3, 1.);
+    let _ = a.mul_mat_strassen(&b, 1);
+}
@@ -5,7 +5,7 @@ fn test_vector_sub() {
     let mut u = V!([2., 3.]);
     let v = V!([5., 7.]);
     u.sub(&v);
-    assert!(u._d.iter().eq(&[-3., -4.]));
+    assert!(u.iter().eq(&[-3., -4.]));
 }
 
 #[test]
@@ -13,7 +13,7 @@ fn test_matrix_sub() {
     let mut u = M!([[1., 2.], [3., 4.]]);
     let v = M!([[7., 4.], [-2., 2.]]);
     u.sub(&v);
-    assert!(u._d.iter().eq(&[-6., -2., 5., 2.]));
+    assert!(u.iter().eq(&[-6., -2., 5., 2.]));
 }
 
 #[test]
@@ -55,7 +55,7 @@ fn test_sub_complex_vector() {
     let mut u = V!([C!(1., 2.), C!(-1., -3.)]);
     let v = V!([C!(1., 3.), C!(2., 3.)]);
     u.sub(&v);
-    assert!(u._d.iter().eq(&[C!(0., -1.), C!(-3., -6.)]));
+    assert!(u.iter().eq(&[C!(0., -1.), C!(-3., -6.)]));
 }
 
 #[test]
@@ -63,7 +63,7 @@ fn test_sub_complex_matrix() {
     let mut u = M!([[C!(1., 0.), C!(2., 0.)], [C!(3., 0.), C!(5., 0.)],]);
     let v = M!([[C!(7., 0.), C!(3., 0.)], [C!(-2., 0.), C!(2., 0.)],]);
     u.sub(&v);
-    assert!(u._d.iter().eq(&[
+    assert!(u.iter().eq(&[
         C!(-6., 0.),
         C!(-1., 0.),
         C!(5., 0.),
@@ -0,0 +1,137 @@
+use std::f64::consts::PI;
+
+use matrix::{
+    angle_cos, approx_eq, Complex, ComplexField, Dot, Rational, Vector, C, V,
+};
+
+fn close(u: &Vector<f64>, v: &[f64]) -> bool {
+    u.size() == v.len() && u.iter().zip(v).all(|(a, b)| approx_eq!(*a, *b))
+}
+
+#[test]
+fn test_angle() {
+    assert!(approx_eq!(V!([1., 0.]).angle(&V!([0., 3.])), PI / 2.));
+    assert!(approx_eq!(V!([1., 1.]).angle(&V!([-2., -2.])), PI));
+    assert!(approx_eq!(
+        V!([1., 0., 0.]).angle(&V!([1., 1., 0.])),
+        PI / 4.
+    ));
+    assert_eq!(V!([2., 0.]).angle(&V!([5., 0.])), 0.);
+
+    let (u, v): (Vector<f64>, _) =
+        (V!([1., 2., 3., 4.]), V!([-1., 0., 2., 0.5]));
+    assert!(approx_eq!(u.angle(&v).cos(), angle_cos(&u, &v)));
+
+    // acos loses everything below about 1e-8 near parallel vectors
+    let u: Vector<f64> = V!([1., 0.]);
+    let v = V!([1., 1e-10]);
+    assert_eq!(angle_cos(&u, &v).acos(), 0.);
+    assert!((u.angle(&v) - 1e-10).abs() < 1e-24);
+    let w = V!([-1., 1e-10]);
+    assert!((u.angle(&w) - (PI - 1e-10)).abs() < 1e-15);
+
+    let c = V!([C!(1., 0.), C!(0., 1.)]);
+    assert!(approx_eq!(c.angle(&c), 0.));
+    assert!(approx_eq!(c.angle(&V!([C!(0., 1.), C!(1., 0.)])), PI / 2.));
+}
+
+#[test]
+fn test_project_reject_reflect() {
+    let u = V!([3., 4., 5.]);
+    let v = V!([2., 0., 0.]);
+    assert!(close(&u.project(&v), &[3., 0., 0.]));
+    assert!(close(&u.reject(&v), &[0., 4., 5.]));
+    assert!(approx_eq!(u.reject(&v).dot(&v), 0.));
+
+    let p = u.project(&u);
+    assert!(close(&p, &[3., 4., 5.]));
+
+    // reflection keeps the length and flips the normal component
+    let n = V!([1., 1., 0.]);
+    let r = u.reflect(&n);
+    assert!(close(&r, &[-4., -3., 5.]));
+    assert!(approx_eq!(r.norm(), u.norm()));
+    assert!(close(&r.reflect(&n), &[3., 4., 5.]));
+    assert!(close(&u.reflect(&(n.clone() * 7.)), &[-4., -3., 5.]));
+
+    // exact over the rationals
+    let q = |n: i64| Rational::from(n);
+    let a = V!([q(1), q(2)]);
+    let b = V!([q(3), q(1)]);
+    let pr = a.project(&b);
+    assert_eq!(pr[0], Rational::new(3, 2));
+    assert_eq!(pr[1], Rational::new(1, 2));
+    assert_eq!(a.reject(&b).dot(&b), q(0));
+
+    // the complex projection uses the conjugating dot product
+    let c = V!([C!(1., 1.), C!(2., 0.)]);
+    let d = V!([C!(0., 1.), C!(1., 0.)]);
+    let rej = c.reject(&d);
+    assert!(approx_eq!(rej.dot(&d).abs(), 0.));
+    assert!(approx_eq!((c.project(&d) + rej - c).norm(), 0.));
+
+    // short vectors are valid directions even when |v|^2 is tiny
+    let s = V!([1e-6, 0.]);
+    assert!(close(&V!([1., 2.]).project(&s), &[1., 0.]));
+    assert!(close(&V!([1., 2.]).reject(&s), &[0., 2.]));
+    assert!(close(&V!([1., 2.]).reflect(&s), &[-1., 2.]));
+}
+
+#[test]
+#[should_panic(expected = "vector must be non-zero")]
+fn test_project_onto_zero() {
+    let _ = V!([1., 2.]).project(&V!([0., 0.]));
+}
+
+#[test]
+fn test_normalize() {
+    let u = V!([3., 0., -4.]);
+    assert!(close(&u.normalize(), &[0.6, 0., -0.8]));
+    assert!(approx_eq!(u.normalize().norm(), 1.));
+    assert!(V!([0., 0.]).try_normalize().is_none());
+    // a short vector is still a direction
+    let t = V!([0., 1e-20]).try_normalize().unwrap();
+    assert!(close(&t, &[0., 1.]));
+
+    let c = V!([C!(3., 4.)]);
+    let n = c.try_normalize().unwrap();
+    assert!(approx_eq!(n[0].x, 0.6) && approx_eq!(n[0].y, 0.8));
+
+    let q = |n: i64| Rational::from(n);
+    let r = V!([q(5), q(12)]).normalize();
+    assert_eq!(r[0], Rational::new(5, 13));
+}
+
+#[test]
+#[should_panic(expected = "vector must be non-zero")]
+fn test_normalize_zero() {
+    let _ = V!([0., 0., 0.]).normalize();
+}
+
+#[test]
+fn test_distance() {
+    let (u, v) = (V!([1., -2., 3.]), V!([4., 2., 3.]));
+    assert_eq!(u.distance(&v), 5.);
+    assert_eq!(u.distance_1(&v), 7.);
+    assert_eq!(u.distance_inf(&v), 4.);
+    assert_eq!(u.distance(&u), 0.);
+    assert_eq!(u.distance(&v), v.distance(&u));
+
+    let c = V!([C!(0., 0.), C!(1., 1.)]);
+    let d = V!([C!(3., 4.), C!(1., 1.)]);
+    assert_eq!(c.distance(&d), 5.);
+}
+
+#[test]
+fn test_triple_product() {
+    let (i, j, k) = (V!([1., 0., 0.]), V!([0., 1., 0.]), V!([0., 0., 1.]));
+    assert_eq!(i.triple_product(&j, &k), 1.);
+    assert_eq!(j.triple_product(&i, &k), -1.);
+    assert_eq!(i.triple_product(&i, &k), 0.);
+
+    let (a, b, c): (Vector<i64>, _, _) =
+        (V!([2, 1, 0]), V!([0, 3, 1]), V!([1, 0, 4]));
+    assert_eq!(a.triple_product(&b, &c), 25);
+    assert_eq!(b.triple_product(&c, &a), 25);
+    assert_eq!(c.triple_product(&b, &a), -25);
+}